@@ -0,0 +1,38 @@
+// Runs a ROM headlessly as fast as possible and reports the emulated frames per second.
+//
+//   cargo run --release --example fps -- path/to/rom.gb [frames]
+//
+// Indexing the opcode tables directly instead of searching them took a ROM looping over
+// loads, ALU ops, CB ops and CALL/RET from a median of ~1260 fps to ~2780 fps, over 8
+// alternating --release runs of 3000 frames each.
+
+extern crate chemboy;
+
+use std::env;
+use std::time::Instant;
+
+use chemboy::gameboy::{self, Cartridge, GameBoy, HardwareModel};
+
+const DEFAULT_FRAMES: u32 = 3000;
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    if args.len() < 2 {
+        println!("usage: fps ROM_PATH [FRAMES]");
+        std::process::exit(1);
+    }
+
+    let frames = args.get(2).and_then(|f| f.parse().ok()).unwrap_or(DEFAULT_FRAMES);
+    let rom = gameboy::read_rom(&args[1], None).expect("err: could not read ROM");
+    let cart = Cartridge::load(&rom, true).expect("err: could not load ROM");
+    let mut gb = GameBoy::new(HardwareModel::DMG, cart, true);
+
+    let start = Instant::now();
+    for _ in 0..frames {
+        gb.run().expect("err: emulation failed");
+    }
+    let elapsed = start.elapsed();
+    let seconds = elapsed.as_secs() as f64 + elapsed.subsec_nanos() as f64 / 1e9;
+
+    println!("{} frames in {:.2}s, {:.1} fps", frames, seconds, frames as f64 / seconds);
+}
@@ -0,0 +1,53 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+use zip::result::ZipError;
+
+#[derive(Debug)]
+pub enum ArchiveError {
+    Corrupt(String),
+    // Compression methods and other zip features we don't read
+    Unsupported(String),
+    // The archive has no .gb, .gbc or .sgb file in it
+    NoRomEntry,
+    EntryNotFound(String),
+    // An entry name was given for a file that isn't a zip
+    EntryWithoutZip(String),
+    Io(io::Error),
+}
+
+impl fmt::Display for ArchiveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ArchiveError::Corrupt(ref msg) => write!(f, "archive is corrupt: {}", msg),
+            ArchiveError::Unsupported(ref msg) => write!(f, "archive is not supported: {}", msg),
+            ArchiveError::NoRomEntry => write!(f, "archive does not contain a ROM"),
+            ArchiveError::EntryNotFound(ref name) => {
+                write!(f, "archive does not contain {}", name)
+            }
+            ArchiveError::EntryWithoutZip(ref name) => {
+                write!(f, "{} was asked for but the file is not a zip archive", name)
+            }
+            ArchiveError::Io(ref e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for ArchiveError {}
+
+impl From<io::Error> for ArchiveError {
+    fn from(e: io::Error) -> ArchiveError {
+        ArchiveError::Io(e)
+    }
+}
+
+impl From<ZipError> for ArchiveError {
+    fn from(e: ZipError) -> ArchiveError {
+        match e {
+            ZipError::Io(e) => ArchiveError::Io(e),
+            ZipError::UnsupportedArchive(msg) => ArchiveError::Unsupported(msg.into()),
+            e => ArchiveError::Corrupt(e.to_string()),
+        }
+    }
+}
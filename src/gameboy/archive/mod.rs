@@ -0,0 +1,169 @@
+// Reads ROMs that are stored as-is, gzipped or inside a zip archive
+
+mod error;
+
+pub use self::error::ArchiveError;
+
+use std::fs::File;
+use std::io::{Cursor, Read};
+use std::path::Path;
+
+use flate2::read::GzDecoder;
+use zip::ZipArchive;
+
+const ROM_EXTENSIONS: [&'static str; 3] = ["gb", "gbc", "sgb"];
+
+// Without an entry name, the first file in a zip with a ROM extension is used
+pub fn read_rom<P>(p: P, entry: Option<&str>) -> Result<Vec<u8>, ArchiveError>
+    where P: AsRef<Path>
+{
+    let mut data = Vec::new();
+    File::open(p)?.read_to_end(&mut data)?;
+
+    unpack(data, entry)
+}
+
+fn unpack(data: Vec<u8>, entry: Option<&str>) -> Result<Vec<u8>, ArchiveError> {
+    if data.starts_with(&[0x50, 0x4B, 0x03, 0x04]) || data.starts_with(&[0x50, 0x4B, 0x05, 0x06]) {
+        return read_zip_entry(&data, entry);
+    }
+
+    // Only zips hold more than one file, so an entry name anywhere else is a mistake
+    if let Some(name) = entry {
+        return Err(ArchiveError::EntryWithoutZip(name.into()));
+    }
+
+    if data.starts_with(&[0x1F, 0x8B]) {
+        decompress(GzDecoder::new(&data[..]))
+    } else {
+        Ok(data)
+    }
+}
+
+fn read_zip_entry(data: &[u8], name: Option<&str>) -> Result<Vec<u8>, ArchiveError> {
+    let mut archive = ZipArchive::new(Cursor::new(data))?;
+
+    let mut names = Vec::with_capacity(archive.len());
+    for i in 0..archive.len() {
+        names.push(archive.by_index(i)?.name().to_string());
+    }
+
+    let index = match name {
+        Some(name) => {
+            names.iter()
+                .position(|e| e == name || Path::new(e).file_name() == Some(name.as_ref()))
+                .ok_or_else(|| ArchiveError::EntryNotFound(name.into()))?
+        }
+        None => names.iter().position(|e| is_rom_name(e)).ok_or(ArchiveError::NoRomEntry)?,
+    };
+
+    let entry = archive.by_index(index)?;
+    decompress(entry)
+}
+
+// Both decoders check the CRC-32 as the end of the data is read
+fn decompress<R>(mut r: R) -> Result<Vec<u8>, ArchiveError>
+    where R: Read
+{
+    let mut out = Vec::new();
+    r.read_to_end(&mut out).map_err(|e| ArchiveError::Corrupt(e.to_string()))?;
+
+    Ok(out)
+}
+
+fn is_rom_name(name: &str) -> bool {
+    Path::new(name)
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|e| ROM_EXTENSIONS.iter().any(|r| e.eq_ignore_ascii_case(r)))
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Write};
+
+    use flate2::Compression;
+    use flate2::read::GzDecoder;
+    use flate2::write::GzEncoder;
+    use zip::ZipWriter;
+    use zip::write::FileOptions;
+
+    use gameboy::ArchiveError;
+    use super::{decompress, unpack};
+
+    fn gunzip(data: &[u8]) -> Result<Vec<u8>, ArchiveError> {
+        decompress(GzDecoder::new(data))
+    }
+
+    fn gzip(data: &[u8]) -> Vec<u8> {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(data).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    // Each file's contents are its own name, so tests can see which one was picked
+    fn zipped(names: &[&str]) -> Vec<u8> {
+        let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+        for name in names {
+            writer.start_file(*name, FileOptions::default()).unwrap();
+            writer.write_all(name.as_bytes()).unwrap();
+        }
+        writer.finish().unwrap().into_inner()
+    }
+
+    fn unzip(names: &[&str], entry: Option<&str>) -> Result<String, ArchiveError> {
+        unpack(zipped(names), entry).map(|data| String::from_utf8(data).unwrap())
+    }
+
+    #[test]
+    fn gzip_round_trips() {
+        let rom: Vec<u8> = (0..0x8000).map(|i| (i * 7) as u8).collect();
+
+        assert_eq!(gunzip(&gzip(&rom)).unwrap(), rom);
+        assert_eq!(unpack(gzip(&rom), None).unwrap(), rom);
+    }
+
+    #[test]
+    fn truncated_gzip_headers_are_errors() {
+        // FEXTRA claiming more bytes than the file has
+        assert!(gunzip(&[0x1F, 0x8B, 0x08, 0x04, 0, 0, 0, 0, 0, 0, 0xFF, 0xFF, 0x00]).is_err());
+        // FNAME with no terminator
+        assert!(gunzip(&[0x1F, 0x8B, 0x08, 0x08, 0, 0, 0, 0, 0, 0, b'a', b'.', b'g']).is_err());
+    }
+
+    #[test]
+    fn zip_picks_the_first_rom() {
+        let names = ["readme.txt", "roms/Game.GBC", "game.gb"];
+
+        assert_eq!("roms/Game.GBC", unzip(&names, None).unwrap());
+        match unzip(&["readme.txt", "game.sav"], None) {
+            Err(ArchiveError::NoRomEntry) => {}
+            other => panic!("expected NoRomEntry, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn zip_entries_match_by_path_or_file_name() {
+        let names = ["a/game.gb", "b/game.gb", "b/other.gb", "notes.txt"];
+
+        assert_eq!("b/game.gb", unzip(&names, Some("b/game.gb")).unwrap());
+        assert_eq!("b/other.gb", unzip(&names, Some("other.gb")).unwrap());
+        assert_eq!("a/game.gb", unzip(&names, Some("game.gb")).unwrap());
+        assert_eq!("notes.txt", unzip(&names, Some("notes.txt")).unwrap());
+        match unzip(&names, Some("c/game.gb")) {
+            Err(ArchiveError::EntryNotFound(ref name)) if name == "c/game.gb" => {}
+            other => panic!("expected EntryNotFound, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn entries_are_only_for_zips() {
+        for data in vec![gzip(b"rom"), b"rom".to_vec()] {
+            match unpack(data, Some("game.gb")) {
+                Err(ArchiveError::EntryWithoutZip(_)) => {}
+                other => panic!("expected EntryWithoutZip, got {:?}", other),
+            }
+        }
+    }
+}
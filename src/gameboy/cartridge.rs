@@ -1,52 +1,134 @@
+use std::fs::File;
+use std::io::{self, Read, Write};
 use std::ops::{Deref, DerefMut};
+use std::path::{Path, PathBuf};
 
+use gameboy::cartridge_details::CartridgeDetails;
+use gameboy::cartridge_error::CartridgeError;
 use gameboy::interconnect::Interconnect;
-use gameboy::mbc::{MBC, MBC0, MBC1};
+use gameboy::mbc::{HuC1, HuC3, MBC, MBC0, MBC1, MBC2, MBC3, MBC5, MBC7, PocketCamera};
 use gameboy::Memory;
 
 const CART_MEM_SIZE: usize = 0x200000;
 const CART_RAM_SIZE: usize = 0x8000;
 
-pub struct CartridgeDetails {
-    pub game_title: String,
-    pub cartridge_type: u8,
-}
-
 pub struct Cartridge {
     rom_code_size: usize,
     pub mbc: Box<MBC>,
     pub details: CartridgeDetails,
+    pub battery: bool,
+
+    save_path: Option<PathBuf>,
+    last_save: Vec<u8>,
 }
 
 impl Cartridge {
-    pub fn with_rom(rom: &[u8]) -> Cartridge {
+    pub fn with_rom(rom: &[u8]) -> Result<Cartridge, CartridgeError> {
+        Self::load(rom, false)
+    }
+
+    // Warning-level problems (see `CartridgeError::is_warning`) are ignored when
+    // `allow_warnings` is set, everything else is always an error
+    pub fn load(rom: &[u8], allow_warnings: bool) -> Result<Cartridge, CartridgeError> {
+        // The header runs from 0x100 to 0x14F
+        if rom.len() < 0x150 {
+            return Err(CartridgeError::TruncatedRom { len: rom.len() });
+        }
+
         let details = Self::get_details(&rom);
         let cartridge_type = rom[0x147];
-        let mbc = Self::get_controller(cartridge_type, &rom);
+        let mbc = Self::get_controller(cartridge_type, &rom)?;
 
-        Cartridge {
+        if details.rom_size != rom.len() {
+            return Err(CartridgeError::RomSizeMismatch {
+                expected: details.rom_size,
+                actual: rom.len(),
+            });
+        }
+
+        if !details.header_checksum_valid() && !allow_warnings {
+            return Err(CartridgeError::BadHeaderChecksum {
+                expected: details.header_checksum,
+                actual: details.computed_header_checksum,
+            });
+        }
+
+        Ok(Cartridge {
             rom_code_size: rom.len(),
             mbc: mbc,
+            battery: details.kind.battery,
             details: details,
+            save_path: None,
+            last_save: Vec::new(),
+        })
+    }
+
+    pub fn save_ram(&self) -> Vec<u8> {
+        self.mbc.save_ram()
+    }
+
+    pub fn load_ram(&mut self, data: &[u8]) {
+        self.mbc.load_ram(data);
+        self.last_save = self.mbc.save_ram();
+    }
+
+    // Ties the cartridge RAM to a save file, loading it straight away if it already exists.
+    // Cartridges without a battery have nothing to persist, so this does nothing for them.
+    pub fn attach_save_file<P>(&mut self, p: P) -> io::Result<()>
+        where P: AsRef<Path>
+    {
+        if !self.battery {
+            return Ok(());
+        }
+
+        let p = p.as_ref().to_path_buf();
+        if p.exists() {
+            let mut data = Vec::new();
+            File::open(&p)?.read_to_end(&mut data)?;
+            self.load_ram(&data);
         }
+        self.save_path = Some(p);
+
+        Ok(())
     }
 
-    pub fn get_details(rom: &[u8]) -> CartridgeDetails {
-        let game_title = String::from_utf8_lossy(&rom[0x134..0x142]).into();
-        let cartridge_type = rom[0x147];
+    // Writes the cartridge RAM out to the attached save file, if it has changed since the last flush
+    pub fn flush_save_file(&mut self) -> io::Result<()> {
+        let p = match self.save_path {
+            Some(ref p) => p.clone(),
+            None => return Ok(()),
+        };
 
-        CartridgeDetails {
-            game_title: game_title,
-            cartridge_type: cartridge_type,
+        let data = self.mbc.save_ram();
+        if data == self.last_save {
+            return Ok(());
         }
+
+        File::create(p)?.write_all(&data)?;
+        self.last_save = data;
+
+        Ok(())
     }
 
-    fn get_controller(b: u8, rom: &[u8]) -> Box<MBC> {
-        match b {
+    pub fn get_details(rom: &[u8]) -> CartridgeDetails {
+        CartridgeDetails::from_rom(rom)
+    }
+
+    fn get_controller(b: u8, rom: &[u8]) -> Result<Box<MBC>, CartridgeError> {
+        let mbc: Box<MBC> = match b {
             0x00 => Box::new(MBC0::new(rom)),
             0x01...0x03 => Box::new(MBC1::new(rom)),
-            _ => panic!("MBC not supported"),
-        }
+            0x05...0x06 => Box::new(MBC2::new(rom)),
+            0x0F...0x13 => Box::new(MBC3::new(rom)),
+            0x19...0x1E => Box::new(MBC5::new(rom)),
+            0x22 => Box::new(MBC7::new(rom)),
+            0xFC => Box::new(PocketCamera::new(rom)),
+            0xFE => Box::new(HuC3::new(rom)),
+            0xFF => Box::new(HuC1::new(rom)),
+            _ => return Err(CartridgeError::UnsupportedMapper(b)),
+        };
+
+        Ok(mbc)
     }
 }
 
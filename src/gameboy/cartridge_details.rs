@@ -0,0 +1,242 @@
+use std::fmt;
+
+use byteorder::{BigEndian, ByteOrder};
+
+use gameboy::licensee;
+use gameboy::mbc::get_ram_size;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CgbSupport {
+    None,
+    Supported,
+    Required,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Destination {
+    Japanese,
+    NonJapanese,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Mapper {
+    RomOnly,
+    MBC1,
+    MBC2,
+    MMM01,
+    MBC3,
+    MBC5,
+    MBC6,
+    MBC7,
+    PocketCamera,
+    BandaiTama5,
+    HuC1,
+    HuC3,
+    Unknown(u8),
+}
+
+// The cartridge type byte at 0x147, decoded into the hardware it describes
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CartridgeKind {
+    pub mapper: Mapper,
+    pub ram: bool,
+    pub battery: bool,
+    pub timer: bool,
+    pub rumble: bool,
+}
+
+impl CartridgeKind {
+    pub fn from_u8(b: u8) -> CartridgeKind {
+        use self::Mapper::*;
+
+        let (mapper, ram, battery, timer, rumble) = match b {
+            0x00 => (RomOnly, false, false, false, false),
+            0x01 => (MBC1, false, false, false, false),
+            0x02 => (MBC1, true, false, false, false),
+            0x03 => (MBC1, true, true, false, false),
+            0x05 => (MBC2, false, false, false, false),
+            0x06 => (MBC2, false, true, false, false),
+            0x08 => (RomOnly, true, false, false, false),
+            0x09 => (RomOnly, true, true, false, false),
+            0x0B => (MMM01, false, false, false, false),
+            0x0C => (MMM01, true, false, false, false),
+            0x0D => (MMM01, true, true, false, false),
+            0x0F => (MBC3, false, true, true, false),
+            0x10 => (MBC3, true, true, true, false),
+            0x11 => (MBC3, false, false, false, false),
+            0x12 => (MBC3, true, false, false, false),
+            0x13 => (MBC3, true, true, false, false),
+            0x19 => (MBC5, false, false, false, false),
+            0x1A => (MBC5, true, false, false, false),
+            0x1B => (MBC5, true, true, false, false),
+            0x1C => (MBC5, false, false, false, true),
+            0x1D => (MBC5, true, false, false, true),
+            0x1E => (MBC5, true, true, false, true),
+            0x20 => (MBC6, true, true, false, false),
+            0x22 => (MBC7, true, true, false, true),
+            0xFC => (PocketCamera, true, true, false, false),
+            0xFD => (BandaiTama5, true, true, false, false),
+            0xFE => (HuC3, true, true, true, false),
+            0xFF => (HuC1, true, true, false, false),
+            n @ _ => (Unknown(n), false, false, false, false),
+        };
+
+        CartridgeKind {
+            mapper: mapper,
+            ram: ram,
+            battery: battery,
+            timer: timer,
+            rumble: rumble,
+        }
+    }
+}
+
+impl fmt::Display for CartridgeKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.mapper {
+            Mapper::RomOnly => write!(f, "ROM")?,
+            Mapper::PocketCamera => write!(f, "POCKET CAMERA")?,
+            Mapper::BandaiTama5 => write!(f, "BANDAI TAMA5")?,
+            Mapper::Unknown(b) => write!(f, "UNKNOWN ({:02X})", b)?,
+            ref m @ _ => write!(f, "{:?}", m)?,
+        }
+
+        if self.timer {
+            write!(f, "+TIMER")?;
+        }
+        if self.rumble {
+            write!(f, "+RUMBLE")?;
+        }
+        if self.ram {
+            write!(f, "+RAM")?;
+        }
+        if self.battery {
+            write!(f, "+BATTERY")?;
+        }
+
+        Ok(())
+    }
+}
+
+pub struct CartridgeDetails {
+    pub game_title: String,
+    pub cartridge_type: u8,
+    pub kind: CartridgeKind,
+    pub cgb: CgbSupport,
+    pub sgb: bool,
+    pub licensee_code: String,
+    pub publisher: Option<&'static str>,
+    pub rom_size: usize,
+    pub ram_size: usize,
+    pub destination: Destination,
+    pub mask_rom_version: u8,
+    pub header_checksum: u8,
+    pub computed_header_checksum: u8,
+    pub global_checksum: u16,
+    pub computed_global_checksum: u16,
+}
+
+impl CartridgeDetails {
+    pub fn from_rom(rom: &[u8]) -> CartridgeDetails {
+        let cgb = match rom[0x143] {
+            0x80 => CgbSupport::Supported,
+            0xC0 => CgbSupport::Required,
+            _ => CgbSupport::None,
+        };
+
+        // Later cartridges shortened the title to make room for the CGB flag
+        let title_end = if cgb == CgbSupport::None { 0x144 } else { 0x143 };
+        let game_title = String::from_utf8_lossy(&rom[0x134..title_end])
+            .trim_end_matches('\0')
+            .trim()
+            .into();
+
+        let (licensee_code, publisher) = if rom[0x14B] == 0x33 {
+            let code = String::from_utf8_lossy(&rom[0x144..0x146]).into_owned();
+            let publisher = licensee::new_licensee_name(&code);
+            (code, publisher)
+        } else {
+            (format!("{:02X}", rom[0x14B]), licensee::old_licensee_name(rom[0x14B]))
+        };
+
+        CartridgeDetails {
+            game_title: game_title,
+            cartridge_type: rom[0x147],
+            kind: CartridgeKind::from_u8(rom[0x147]),
+            cgb: cgb,
+            sgb: rom[0x146] == 0x03,
+            licensee_code: licensee_code,
+            publisher: publisher,
+            rom_size: Self::get_rom_size(rom[0x148]),
+            ram_size: get_ram_size(rom[0x149]),
+            destination: if rom[0x14A] == 0x00 {
+                Destination::Japanese
+            } else {
+                Destination::NonJapanese
+            },
+            mask_rom_version: rom[0x14C],
+            header_checksum: rom[0x14D],
+            computed_header_checksum: Self::compute_header_checksum(rom),
+            global_checksum: BigEndian::read_u16(&rom[0x14E..]),
+            computed_global_checksum: Self::compute_global_checksum(rom),
+        }
+    }
+
+    pub fn get_rom_size(b: u8) -> usize {
+        match b {
+            0x00...0x08 => 0x8000 << b,
+            0x52 => 0x4000 * 72,
+            0x53 => 0x4000 * 80,
+            0x54 => 0x4000 * 96,
+            _ => 0x00,
+        }
+    }
+
+    // The boot ROM refuses to start the game if this doesn't match the byte at 0x14D
+    pub fn compute_header_checksum(rom: &[u8]) -> u8 {
+        rom[0x134..0x14D]
+            .iter()
+            .fold(0u8, |x, b| x.wrapping_sub(*b).wrapping_sub(0x01))
+    }
+
+    // Sum of every byte in the ROM except the checksum itself. Real hardware never checks it.
+    pub fn compute_global_checksum(rom: &[u8]) -> u16 {
+        rom.iter()
+            .enumerate()
+            .filter(|&(i, _)| i != 0x14E && i != 0x14F)
+            .fold(0u16, |x, (_, b)| x.wrapping_add(*b as u16))
+    }
+
+    pub fn header_checksum_valid(&self) -> bool {
+        self.header_checksum == self.computed_header_checksum
+    }
+
+    pub fn global_checksum_valid(&self) -> bool {
+        self.global_checksum == self.computed_global_checksum
+    }
+}
+
+impl fmt::Display for CartridgeDetails {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Title:            {}", self.game_title)?;
+        writeln!(f, "Cartridge type:   {} ({:02X})", self.kind, self.cartridge_type)?;
+        writeln!(f, "CGB support:      {:?}", self.cgb)?;
+        writeln!(f, "SGB support:      {}", self.sgb)?;
+        writeln!(f,
+                 "Publisher:        {} ({})",
+                 self.publisher.unwrap_or("Unknown"),
+                 self.licensee_code)?;
+        writeln!(f, "ROM size:         {} KiB", self.rom_size / 0x400)?;
+        writeln!(f, "RAM size:         {} KiB", self.ram_size / 0x400)?;
+        writeln!(f, "Destination:      {:?}", self.destination)?;
+        writeln!(f, "Mask ROM version: {}", self.mask_rom_version)?;
+        writeln!(f,
+                 "Header checksum:  {:02X} ({})",
+                 self.header_checksum,
+                 if self.header_checksum_valid() { "OK" } else { "BAD" })?;
+        write!(f,
+               "Global checksum:  {:04X} ({})",
+               self.global_checksum,
+               if self.global_checksum_valid() { "OK" } else { "BAD" })
+    }
+}
@@ -0,0 +1,49 @@
+use std::error::Error;
+use std::fmt;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CartridgeError {
+    // The ROM is too small to even contain a cartridge header
+    TruncatedRom { len: usize },
+    UnsupportedMapper(u8),
+    RomSizeMismatch { expected: usize, actual: usize },
+    // Real hardware refuses to boot these, but plenty of homebrew and hacked ROMs
+    // never fix up the checksum, so this one can be overridden
+    BadHeaderChecksum { expected: u8, actual: u8 },
+}
+
+impl CartridgeError {
+    pub fn is_warning(&self) -> bool {
+        match *self {
+            CartridgeError::BadHeaderChecksum { .. } => true,
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for CartridgeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CartridgeError::TruncatedRom { len } => {
+                write!(f, "ROM is truncated, only {} bytes long", len)
+            }
+            CartridgeError::UnsupportedMapper(b) => {
+                write!(f, "cartridge type {:02X} is not supported", b)
+            }
+            CartridgeError::RomSizeMismatch { expected, actual } => {
+                write!(f,
+                       "header declares {} bytes of ROM but the file is {} bytes",
+                       expected,
+                       actual)
+            }
+            CartridgeError::BadHeaderChecksum { expected, actual } => {
+                write!(f,
+                       "header checksum is {:02X} but the header sums to {:02X}",
+                       expected,
+                       actual)
+            }
+        }
+    }
+}
+
+impl Error for CartridgeError {}
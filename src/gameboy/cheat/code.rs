@@ -0,0 +1,138 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CheatCode {
+    // Substitutes a byte whenever the CPU reads cartridge ROM at `address`. With a compare
+    // byte, it only applies when the ROM actually holds that byte (i.e the right bank is mapped).
+    GameGenie {
+        address: u16,
+        value: u8,
+        compare: Option<u8>,
+    },
+    // Writes a byte to RAM every frame. `bank` is the external RAM bank for 0xA000-0xBFFF,
+    // and None for any other address.
+    GameShark {
+        address: u16,
+        value: u8,
+        bank: Option<u8>,
+    },
+}
+
+impl CheatCode {
+    // Game Genie codes are ABC-DEF or ABC-DEF-GHI, GameShark codes are BBVVLLHH
+    pub fn parse(code: &str) -> Result<CheatCode, CheatError> {
+        let stripped: String = code.chars().filter(|c| *c != '-').collect();
+        let digits = stripped
+            .chars()
+            .map(|c| c.to_digit(16).map(|d| d as u8))
+            .collect::<Option<Vec<u8>>>()
+            .ok_or_else(|| CheatError::InvalidCode(code.into()))?;
+
+        match digits.len() {
+            0x06 | 0x09 => Self::parse_game_genie(code, &digits),
+            0x08 => Ok(Self::parse_game_shark(&digits)),
+            _ => Err(CheatError::InvalidCode(code.into())),
+        }
+    }
+
+    fn parse_game_genie(code: &str, d: &[u8]) -> Result<CheatCode, CheatError> {
+        let value = (d[0] << 0x04) | d[1];
+        let address = (((d[5] ^ 0x0F) as u16) << 0x0C) | ((d[2] as u16) << 0x08) |
+                      ((d[3] as u16) << 0x04) | d[4] as u16;
+
+        if address > 0x7FFF {
+            return Err(CheatError::InvalidCode(code.into()));
+        }
+
+        // The compare byte is digits G and I, rotated and scrambled. H is unused.
+        let compare = if d.len() == 0x09 {
+            let c = (d[6] << 0x04) | d[8];
+            Some(c.rotate_right(0x02) ^ 0xBA)
+        } else {
+            None
+        };
+
+        Ok(CheatCode::GameGenie {
+            address: address,
+            value: value,
+            compare: compare,
+        })
+    }
+
+    // The first byte is the external RAM bank the write goes to. It only means anything for
+    // cartridge RAM, codes for WRAM or HRAM conventionally use 01.
+    fn parse_game_shark(d: &[u8]) -> CheatCode {
+        let bank = (d[0] << 0x04) | d[1];
+        let value = (d[2] << 0x04) | d[3];
+        let address = ((((d[6] << 0x04) | d[7]) as u16) << 0x08) | ((d[4] << 0x04) | d[5]) as u16;
+
+        CheatCode::GameShark {
+            address: address,
+            value: value,
+            bank: match address {
+                0xA000...0xBFFF => Some(bank),
+                _ => None,
+            },
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum CheatError {
+    InvalidCode(String),
+    UnknownCheat(usize),
+    Io(io::Error),
+}
+
+impl fmt::Display for CheatError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CheatError::InvalidCode(ref code) => {
+                write!(f, "{} is not a valid Game Genie or GameShark code", code)
+            }
+            CheatError::UnknownCheat(id) => write!(f, "no cheat with id {}", id),
+            CheatError::Io(ref e) => write!(f, "could not read cheat file: {}", e),
+        }
+    }
+}
+
+impl Error for CheatError {}
+
+impl From<io::Error> for CheatError {
+    fn from(e: io::Error) -> CheatError {
+        CheatError::Io(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CheatCode;
+
+    #[test]
+    fn game_shark_cart_ram_codes_use_the_first_byte_as_the_bank() {
+        assert_eq!(CheatCode::parse("0163A2B1").unwrap(),
+                   CheatCode::GameShark {
+                       address: 0xB1A2,
+                       value: 0x63,
+                       bank: Some(0x01),
+                   });
+        assert_eq!(CheatCode::parse("03FF00A0").unwrap(),
+                   CheatCode::GameShark {
+                       address: 0xA000,
+                       value: 0xFF,
+                       bank: Some(0x03),
+                   });
+    }
+
+    #[test]
+    fn game_shark_wram_codes_have_no_bank() {
+        assert_eq!(CheatCode::parse("010F34D1").unwrap(),
+                   CheatCode::GameShark {
+                       address: 0xD134,
+                       value: 0x0F,
+                       bank: None,
+                   });
+    }
+}
@@ -0,0 +1,107 @@
+mod code;
+
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+pub use self::code::{CheatCode, CheatError};
+
+pub struct Cheat {
+    pub name: String,
+    pub code: String,
+    pub codes: Vec<CheatCode>,
+    pub enabled: bool,
+}
+
+pub struct Cheats {
+    cheats: Vec<Cheat>,
+}
+
+impl Cheats {
+    pub fn new() -> Cheats {
+        Cheats { cheats: Vec::new() }
+    }
+
+    // Several codes can make up a single cheat, separated by '+'
+    pub fn add<S>(&mut self, name: S, code: &str) -> Result<usize, CheatError>
+        where S: Into<String>
+    {
+        let codes = code.split('+')
+            .map(|c| CheatCode::parse(c.trim()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        self.cheats.push(Cheat {
+            name: name.into(),
+            code: code.into(),
+            codes: codes,
+            enabled: true,
+        });
+
+        Ok(self.cheats.len() - 0x01)
+    }
+
+    // Cheat files have one cheat per line, the code followed by an optional description.
+    // Blank lines and lines starting with '#' are skipped.
+    pub fn load_file<P>(&mut self, p: P) -> Result<usize, CheatError>
+        where P: AsRef<Path>
+    {
+        let mut contents = String::new();
+        File::open(p)?.read_to_string(&mut contents)?;
+
+        let mut count = 0;
+        for line in contents.lines().map(|l| l.trim()) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut parts = line.splitn(2, char::is_whitespace);
+            let code = parts.next().unwrap_or("");
+            let name = parts.next().map(|n| n.trim()).unwrap_or(code);
+            self.add(name, code)?;
+            count += 1;
+        }
+
+        Ok(count)
+    }
+
+    pub fn set_enabled(&mut self, id: usize, enabled: bool) -> Result<(), CheatError> {
+        let cheat = self.cheats.get_mut(id).ok_or(CheatError::UnknownCheat(id))?;
+        cheat.enabled = enabled;
+
+        Ok(())
+    }
+
+    pub fn list(&self) -> &[Cheat] {
+        &self.cheats
+    }
+
+    fn active_codes<'a>(&'a self) -> Box<Iterator<Item = &'a CheatCode> + 'a> {
+        Box::new(self.cheats.iter().filter(|c| c.enabled).flat_map(|c| c.codes.iter()))
+    }
+
+    pub fn patch_rom_read(&self, addr: u16, value: u8) -> u8 {
+        if self.cheats.is_empty() {
+            return value;
+        }
+
+        for code in self.active_codes() {
+            if let CheatCode::GameGenie { address, value: new_value, compare } = *code {
+                if address == addr && compare.map(|c| c == value).unwrap_or(true) {
+                    return new_value;
+                }
+            }
+        }
+
+        value
+    }
+
+    // The (address, value, bank) writes GameShark codes want applied this frame
+    pub fn ram_writes(&self) -> Vec<(u16, u8, Option<u8>)> {
+        self.active_codes()
+            .filter_map(|code| match *code {
+                CheatCode::GameShark { address, value, bank } => Some((address, value, bank)),
+                _ => None,
+            })
+            .collect()
+    }
+}
@@ -1,7 +1,7 @@
 // Simon Whitehead, 2017
 
 use gameboy::registers;
-use gameboy::{Interconnect, Interrupt, MAX_CPU_CYCLES};
+use gameboy::{Interconnect, MAX_CPU_CYCLES, M_CYCLE};
 use gameboy::opcodes::{ArgumentType, OpCode, Operand};
 
 pub enum CpuSpeed {
@@ -13,74 +13,66 @@ pub struct Cpu {
     pub registers: registers::Registers,
     pub speed: CpuSpeed,
     pub halted: bool,
+    pub stopped: bool,
     pub trace_points: [bool; 15],
+
+    // HALT with IME=0 and an interrupt already pending doesn't halt, and the next opcode
+    // byte is read twice
+    halt_bug: bool,
+    // EI only takes effect after the instruction that follows it
+    ime_delay: u8,
 }
 
 impl Cpu {
-    pub fn new(gameboy_color: bool) -> Cpu {
+    pub fn new() -> Cpu {
         Cpu {
-            registers: registers::Registers::new(gameboy_color),
+            registers: registers::Registers::new(),
             speed: CpuSpeed::Normal,
             halted: false,
+            stopped: false,
+            halt_bug: false,
+            ime_delay: 0x00,
             trace_points: [false; 15],
         }
     }
 
     pub fn reset(&mut self, interconnect: &mut Interconnect) {
         interconnect.reset();
-        self.set_initial_values(interconnect);
-        self.registers.pc = 0x00;
-    }
 
-    pub fn set_initial_values(&mut self, interconnect: &mut Interconnect) {
         self.halted = false;
+        self.stopped = false;
+        self.halt_bug = false;
+        self.ime_delay = 0x00;
+
+        if interconnect.booting {
+            self.registers = registers::Registers::new();
+        } else {
+            self.load_post_boot_state(interconnect);
+        }
+    }
+
+    // Skips the boot ROM, starting at 0x0100 with everything as the boot ROM would leave it
+    fn load_post_boot_state(&mut self, interconnect: &mut Interconnect) {
+        let checksum = interconnect.cart_details().header_checksum;
+        let registers = interconnect.model.post_boot_registers(checksum);
+
+        self.registers.set_af(registers.af);
+        self.registers.set_bc(registers.bc);
+        self.registers.set_de(registers.de);
+        self.registers.set_hl(registers.hl);
+        self.registers.sp = registers.sp as usize;
         self.registers.pc = 0x100;
-        self.registers.set_af(0x01B0);
-        self.registers.set_bc(0x0013);
-        self.registers.set_de(0x00D8);
-        self.registers.set_hl(0x014D);
-        self.registers.sp = 0xFFFE;
-
-        interconnect.write_u8(0xFF05, 0x00);
-        interconnect.write_u8(0xFF06, 0x00);
-        interconnect.write_u8(0xFF07, 0x00);
-        interconnect.write_u8(0xFF10, 0x80);
-        interconnect.write_u8(0xFF11, 0xBF);
-        interconnect.write_u8(0xFF12, 0xF3);
-        interconnect.write_u8(0xFF14, 0xBF);
-        interconnect.write_u8(0xFF16, 0x3F);
-        interconnect.write_u8(0xFF17, 0x00);
-        interconnect.write_u8(0xFF19, 0xBF);
-        interconnect.write_u8(0xFF1A, 0x7F);
-        interconnect.write_u8(0xFF1B, 0xFF);
-        interconnect.write_u8(0xFF1C, 0x9F);
-        interconnect.write_u8(0xFF1E, 0xBF);
-        interconnect.write_u8(0xFF20, 0xFF);
-        interconnect.write_u8(0xFF21, 0x00);
-        interconnect.write_u8(0xFF22, 0x00);
-        interconnect.write_u8(0xFF23, 0xBF);
-        interconnect.write_u8(0xFF24, 0x77);
-        interconnect.write_u8(0xFF25, 0xF3);
-        interconnect.write_u8(0xFF26, 0xF1);
-        interconnect.write_u8(0xFF40, 0x91);
-        interconnect.write_u8(0xFF42, 0x00);
-        interconnect.write_u8(0xFF43, 0x00);
-        interconnect.write_u8(0xFF45, 0x00);
-        interconnect.write_u8(0xFF47, 0xFC);
-        interconnect.write_u8(0xFF48, 0xFF);
-        interconnect.write_u8(0xFF49, 0xFF);
-        interconnect.write_u8(0xFF4A, 0x00);
-        interconnect.write_u8(0xFF4B, 0x00);
-        interconnect.write_u8(0xFFFF, 0x00);
-    }
-
-    fn get_operand_from_opcode(&self, interconnect: &Interconnect, opcode: &OpCode) -> Operand {
+
+        interconnect.load_post_boot_state();
+    }
+
+    fn get_operand_from_opcode(&self, interconnect: &mut Interconnect, opcode: &OpCode) -> Operand {
         let operand_start = self.registers.pc + 0x01;
 
         match opcode.argument_type {
             ArgumentType::Implied => Operand::None,
-            ArgumentType::Imm8 => Operand::Imm8(interconnect.read_u8(operand_start)),
-            ArgumentType::Imm16 => Operand::Imm16(interconnect.read_u16(operand_start)),
+            ArgumentType::Imm8 => Operand::Imm8(interconnect.read_cycle_u8(operand_start)),
+            ArgumentType::Imm16 => Operand::Imm16(interconnect.read_cycle_u16(operand_start)),
             _ => panic!("Unknown opcode argument type"),
         }
     }
@@ -88,90 +80,127 @@ impl Cpu {
     pub fn cycle(&mut self, interconnect: &mut Interconnect) -> Result<(), String> {
         let mut cycles = 0;
 
-        while cycles < self.get_cycles_for_speed() {
-            let c = self.step(interconnect)?;
-            cycles += c as usize;
-            interconnect.step(c as usize)?;
-            if self.handle_interrupts(interconnect) > 0x00 {
-                self.halted = false;
-            }
+        while cycles < self.get_cycles_for_speed(interconnect) {
+            cycles += self.step(interconnect)? as usize;
+            self.handle_interrupts(interconnect);
+            cycles += interconnect.take_cycles()?;
         }
 
         Ok(())
     }
 
-    fn get_cycles_for_speed(&self) -> usize {
-        match self.speed {
+    fn get_cycles_for_speed(&self, interconnect: &Interconnect) -> usize {
+        let cycles = match self.speed {
             CpuSpeed::Normal => MAX_CPU_CYCLES,
             CpuSpeed::Double => MAX_CPU_CYCLES << 0x01,
+        };
+
+        // The CGB's double speed mode fits twice as many CPU cycles into a frame
+        if interconnect.double_speed {
+            cycles << 0x01
+        } else {
+            cycles
         }
     }
 
     pub fn handle_interrupts(&mut self, interconnect: &mut Interconnect) -> u8 {
-        // Always handle a LoadGame interrupt whether its enabled or not
-        if interconnect.irq.requested(&Interrupt::LoadGame) {
-            interconnect.irq.unrequest(Interrupt::LoadGame);
-            self.set_initial_values(interconnect);
-            return 0xFF;
+        if interconnect.irq.pending() == 0x00 {
+            return 0x00;
         }
 
+        // A pending interrupt wakes the CPU from HALT even if IME is off, it just isn't serviced
+        let was_halted = self.halted;
+        self.halted = false;
+
         if !interconnect.irq.enabled {
             return 0x00;
         }
 
-        if interconnect.irq.should_handle(Interrupt::Vblank) {
-            interconnect.irq.enabled = false;
-            self.call(0x40, interconnect);
-            interconnect.irq.unrequest(Interrupt::Vblank);
+        interconnect.irq.enabled = false;
 
-            return 0x0C;
+        // Leaving HALT costs an extra M-cycle
+        if was_halted {
+            interconnect.tick();
         }
 
-        if interconnect.irq.should_handle(Interrupt::Lcd) {
-            interconnect.irq.enabled = false;
-            interconnect.irq.unrequest(Interrupt::Lcd);
-            self.call(0x48, interconnect);
+        // Two M-cycles pass before the return address is pushed
+        interconnect.tick();
+        interconnect.tick();
+
+        let pc = self.registers.pc;
+        self.registers.sp = self.registers.sp.wrapping_sub(0x01) & 0xFFFF;
+        interconnect.write_cycle_u8(self.registers.sp as u16, (pc >> 0x08) as u8);
+
+        // The interrupt is only picked after the high byte has been pushed. If that push
+        // landed on IE and left nothing pending, dispatch is cancelled and we jump to 0x0000.
+        let addr = match interconnect.irq.highest_priority() {
+            Some(int) => {
+                let addr = int.vector();
+                interconnect.irq.unrequest(int);
+                addr
+            }
+            None => 0x0000,
+        };
 
-            return 0x0C;
-        }
+        self.registers.sp = self.registers.sp.wrapping_sub(0x01) & 0xFFFF;
+        interconnect.write_cycle_u8(self.registers.sp as u16, pc as u8);
 
-        if interconnect.irq.should_handle(Interrupt::Timer) {
-            interconnect.irq.enabled = false;
-            interconnect.irq.unrequest(Interrupt::Timer);
-            self.call(0x50, interconnect);
+        interconnect.tick();
+        self.registers.pc = addr;
 
-            return 0x0C;
-        }
+        0x14
+    }
 
-        if interconnect.irq.should_handle(Interrupt::Serial) {
-            interconnect.irq.enabled = false;
-            interconnect.irq.unrequest(Interrupt::Serial);
-            self.call(0x58, interconnect);
+    // Runs one instruction, ticking the rest of the system along with it
+    pub fn step(&mut self, interconnect: &mut Interconnect) -> Result<u8, String> {
+        // The system clock is stopped, so nothing else runs until a button press wakes us
+        if self.stopped {
+            if interconnect.joypad.line_low() {
+                self.stopped = false;
+            }
 
-            return 0x0C;
+            return Ok(M_CYCLE as u8);
         }
 
-        if interconnect.irq.should_handle(Interrupt::Joypad) {
-            interconnect.irq.enabled = false;
-            interconnect.irq.unrequest(Interrupt::Joypad);
-            self.call(0x60, interconnect);
+        let cycles = self.execute(interconnect)?;
+
+        if self.ime_delay > 0x00 {
+            self.ime_delay -= 0x01;
+            if self.ime_delay == 0x00 {
+                interconnect.irq.enabled = true;
+            }
+        }
 
-            return 0x0C;
+        // Memory accesses have already ticked their M-cycles, whatever is left was
+        // spent inside the CPU
+        let mut ticked = interconnect.take_cycles()?;
+        while ticked < cycles as usize {
+            interconnect.tick();
+            ticked += M_CYCLE;
         }
+        interconnect.take_cycles()?;
 
-        0x00
+        Ok(ticked as u8)
     }
 
-    pub fn step(&mut self, interconnect: &mut Interconnect) -> Result<u8, String> {
+    fn execute(&mut self, interconnect: &mut Interconnect) -> Result<u8, String> {
         // Do nothing if we're halted
         if self.halted {
-            return Ok(0x01);
+            return Ok(M_CYCLE as u8);
         }
 
-        let byte = interconnect.read_u8(self.registers.pc);
+        let byte = interconnect.read_cycle_u8(self.registers.pc);
+
+        // The HALT bug: PC fails to move past the opcode, so its byte is read again as
+        // whatever comes next
+        if self.halt_bug {
+            self.halt_bug = false;
+            self.registers.pc = self.registers.pc.wrapping_sub(0x01);
+        }
 
         if let Some(opcode) = OpCode::from_byte(byte, false) {
             let mut cycles = opcode.cycles;
+            let mut taken = false;
             let operand = self.get_operand_from_opcode(interconnect, &opcode);
 
             self.registers.pc += opcode.length;
@@ -193,7 +222,7 @@ impl Cpu {
                 0x0D => self.dec_c(),
                 0x0E => self.ld_c_imm8(&operand),
                 0x0F => self.rrca(),
-                0x10 => self.halt(), // We're emulating hardware... just HALT instead of STOP
+                0x10 => self.stop(interconnect),
                 0x11 => self.ld_de_imm16(&operand),
                 0x12 => self.ld_de_a(interconnect),
                 0x13 => self.inc_de(),
@@ -209,7 +238,7 @@ impl Cpu {
                 0x1D => self.dec_e(),
                 0x1E => self.ld_e_imm8(&operand),
                 0x1F => self.rra(),
-                0x20 => self.jr_nz_imm8(&operand),
+                0x20 => taken = self.jr_nz_imm8(&operand),
                 0x21 => self.ld_hl_imm16(&operand),
                 0x22 => self.ld_hli_a(interconnect),
                 0x23 => self.inc_hl(),
@@ -217,7 +246,7 @@ impl Cpu {
                 0x25 => self.dec_h(),
                 0x26 => self.ld_h_imm8(&operand),
                 0x27 => self.daa(),
-                0x28 => self.jr_z_imm8(&operand),
+                0x28 => taken = self.jr_z_imm8(&operand),
                 0x29 => self.add_hl_hl(),
                 0x2A => self.ld_a_hli(interconnect),
                 0x2B => self.dec_hl(),
@@ -225,7 +254,7 @@ impl Cpu {
                 0x2D => self.dec_l(),
                 0x2E => self.ld_l_imm8(&operand),
                 0x2F => self.cpl(),
-                0x30 => self.jr_nc_imm8(&operand),
+                0x30 => taken = self.jr_nc_imm8(&operand),
                 0x31 => self.ld_sp_imm16(&operand),
                 0x32 => self.ld_hld_a(interconnect),
                 0x33 => self.inc_sp(),
@@ -233,7 +262,7 @@ impl Cpu {
                 0x35 => self.dec_hl_ptr(interconnect),
                 0x36 => self.ld_hl_imm8(&operand, interconnect),
                 0x37 => self.scf(),
-                0x38 => self.jr_c_imm8(&operand),
+                0x38 => taken = self.jr_c_imm8(&operand),
                 0x39 => self.add_hl_sp(),
                 0x3A => self.ld_a_hld(interconnect),
                 0x3B => self.dec_sp(),
@@ -295,7 +324,7 @@ impl Cpu {
                 0x73 => self.ld_hl_ptr_e(interconnect),
                 0x74 => self.ld_hl_ptr_h(interconnect),
                 0x75 => self.ld_hl_ptr_l(interconnect),
-                0x76 => self.halt(),
+                0x76 => self.halt(interconnect),
                 0x77 => self.ld_hl_ptr_a(interconnect),
                 0x78 => self.ld_a_b(),
                 0x79 => self.ld_a_c(),
@@ -369,35 +398,35 @@ impl Cpu {
                 0xBD => self.cp_l(),
                 0xBE => self.cp_hl_ptr(interconnect),
                 0xBF => self.cp_a(),
-                0xC0 => self.ret_nz(interconnect),
+                0xC0 => taken = self.ret_nz(interconnect),
                 0xC1 => self.pop_bc(interconnect),
-                0xC2 => self.jp_nz_imm16(&operand),
+                0xC2 => taken = self.jp_nz_imm16(&operand),
                 0xC3 => self.jp_imm16(&operand),
-                0xC4 => self.call_nz_imm16(&operand, interconnect),
+                0xC4 => taken = self.call_nz_imm16(&operand, interconnect),
                 0xC5 => self.push_bc(interconnect),
                 0xC6 => self.add_a_imm8(&operand),
                 0xC7 => self.call(0x00, interconnect),
-                0xC8 => self.ret_z(interconnect),
+                0xC8 => taken = self.ret_z(interconnect),
                 0xC9 => self.ret(interconnect),
-                0xCA => self.jp_z_imm16(&operand),
+                0xCA => taken = self.jp_z_imm16(&operand),
                 0xCB => {
                     cycles = self.handle_extended_opcode(interconnect)?;
                 }
-                0xCC => self.call_z_imm16(&operand, interconnect),
+                0xCC => taken = self.call_z_imm16(&operand, interconnect),
                 0xCD => self.call(operand.unwrap_imm16(), interconnect),
                 0xCE => self.adc_a_imm8(&operand),
                 0xCF => self.call(0x08, interconnect),
-                0xD0 => self.ret_nc(interconnect),
+                0xD0 => taken = self.ret_nc(interconnect),
                 0xD1 => self.pop_de(interconnect),
-                0xD2 => self.jp_nc_imm16(&operand),
-                0xD4 => self.call_nc_imm16(&operand, interconnect),
+                0xD2 => taken = self.jp_nc_imm16(&operand),
+                0xD4 => taken = self.call_nc_imm16(&operand, interconnect),
                 0xD5 => self.push_de(interconnect),
                 0xD6 => self.sub_imm8(&operand),
                 0xD7 => self.call(0x10, interconnect),
-                0xD8 => self.ret_c(interconnect),
+                0xD8 => taken = self.ret_c(interconnect),
                 0xD9 => self.reti(interconnect),
-                0xDA => self.jp_c_imm16(&operand),
-                0xDC => self.call_c_imm16(&operand, interconnect),
+                0xDA => taken = self.jp_c_imm16(&operand),
+                0xDC => taken = self.call_c_imm16(&operand, interconnect),
                 0xDE => self.sbc_a_imm8(&operand),
                 0xDF => self.call(0x18, interconnect),
                 0xE0 => self.ld_ff00_imm8_a(&operand, interconnect),
@@ -432,6 +461,10 @@ impl Cpu {
                 }
             }
 
+            if taken {
+                cycles = opcode.cycles_taken;
+            }
+
             return Ok(cycles);
         }
 
@@ -445,11 +478,9 @@ impl Cpu {
         &mut self,
         interconnect: &mut Interconnect,
     ) -> Result<u8, String> {
-        let byte = interconnect.read_u8(self.registers.pc);
+        let byte = interconnect.read_cycle_u8(self.registers.pc);
 
         if let Some(opcode) = OpCode::from_byte(byte, true) {
-            let operand = self.get_operand_from_opcode(interconnect, &opcode);
-
             // println!("Read 0x{:02X} from 0x{:04X}", byte, self.registers.pc);
             self.registers.pc += opcode.length;
 
@@ -712,15 +743,9 @@ impl Cpu {
                 0xFD => self.set_7_l(),
                 0xFE => self.set_7_hl_ptr(interconnect),
                 0xFF => self.set_7_a(),
-                _ => {
-                    return Err(format!(
-                        "Could not match opcode: {:02X} at offset: {:04X}",
-                        opcode.code, self.registers.pc
-                    ))
-                }
             }
 
-            return Ok(opcode.cycles + 0x01);
+            return Ok(opcode.cycles);
         }
 
         Err(format!(
@@ -788,7 +813,7 @@ impl Cpu {
 
     fn adc_a_hl_ptr(&mut self, interconnect: &mut Interconnect) {
         let a = self.registers.a;
-        let val = interconnect.read_u8(self.registers.get_hl());
+        let val = interconnect.read_cycle_u8(self.registers.get_hl());
 
         self.adc(a, val);
     }
@@ -881,7 +906,7 @@ impl Cpu {
 
     fn add_a_hl_ptr(&mut self, interconnect: &mut Interconnect) {
         let a1 = self.registers.a;
-        let a2 = interconnect.read_u8(self.registers.get_hl());
+        let a2 = interconnect.read_cycle_u8(self.registers.get_hl());
 
         self.registers.a = a1.wrapping_add(a2);
 
@@ -1015,7 +1040,7 @@ impl Cpu {
     }
 
     fn and_hl_ptr(&mut self, interconnect: &mut Interconnect) {
-        let val = interconnect.read_u8(self.registers.get_hl());
+        let val = interconnect.read_cycle_u8(self.registers.get_hl());
         self.and(val);
     }
 
@@ -1069,7 +1094,7 @@ impl Cpu {
     }
 
     fn bit_0_hl_ptr(&mut self, interconnect: &mut Interconnect) {
-        let val = interconnect.read_u8(self.registers.get_hl());
+        let val = interconnect.read_cycle_u8(self.registers.get_hl());
         self.bit(val, 0x00);
     }
 
@@ -1109,7 +1134,7 @@ impl Cpu {
     }
 
     fn bit_1_hl_ptr(&mut self, interconnect: &mut Interconnect) {
-        let val = interconnect.read_u8(self.registers.get_hl());
+        let val = interconnect.read_cycle_u8(self.registers.get_hl());
         self.bit(val, 0x01);
     }
 
@@ -1149,7 +1174,7 @@ impl Cpu {
     }
 
     fn bit_2_hl_ptr(&mut self, interconnect: &mut Interconnect) {
-        let val = interconnect.read_u8(self.registers.get_hl());
+        let val = interconnect.read_cycle_u8(self.registers.get_hl());
         self.bit(val, 0x02);
     }
 
@@ -1189,7 +1214,7 @@ impl Cpu {
     }
 
     fn bit_3_hl_ptr(&mut self, interconnect: &mut Interconnect) {
-        let val = interconnect.read_u8(self.registers.get_hl());
+        let val = interconnect.read_cycle_u8(self.registers.get_hl());
         self.bit(val, 0x03);
     }
 
@@ -1229,7 +1254,7 @@ impl Cpu {
     }
 
     fn bit_4_hl_ptr(&mut self, interconnect: &mut Interconnect) {
-        let val = interconnect.read_u8(self.registers.get_hl());
+        let val = interconnect.read_cycle_u8(self.registers.get_hl());
         self.bit(val, 0x04);
     }
 
@@ -1269,7 +1294,7 @@ impl Cpu {
     }
 
     fn bit_5_hl_ptr(&mut self, interconnect: &mut Interconnect) {
-        let val = interconnect.read_u8(self.registers.get_hl());
+        let val = interconnect.read_cycle_u8(self.registers.get_hl());
         self.bit(val, 0x05);
     }
 
@@ -1309,7 +1334,7 @@ impl Cpu {
     }
 
     fn bit_6_hl_ptr(&mut self, interconnect: &mut Interconnect) {
-        let val = interconnect.read_u8(self.registers.get_hl());
+        let val = interconnect.read_cycle_u8(self.registers.get_hl());
         self.bit(val, 0x06);
     }
 
@@ -1349,7 +1374,7 @@ impl Cpu {
     }
 
     fn bit_7_hl_ptr(&mut self, interconnect: &mut Interconnect) {
-        let val = interconnect.read_u8(self.registers.get_hl());
+        let val = interconnect.read_cycle_u8(self.registers.get_hl());
         self.bit(val, 0x07);
     }
 
@@ -1359,37 +1384,58 @@ impl Cpu {
     }
 
     fn call(&mut self, addr: u16, interconnect: &mut Interconnect) {
-        self.registers.sp -= 0x02;
-        interconnect.write_u16(self.registers.sp as u16, self.registers.pc);
+        let pc = self.registers.pc;
+        self.push_u16(pc, interconnect);
         self.registers.pc = addr;
     }
 
-    fn call_c_imm16(&mut self, operand: &Operand, interconnect: &mut Interconnect) {
-        if self.registers.flags.carry {
+    // The CPU spends an M-cycle decrementing SP, then writes the high byte first
+    fn push_u16(&mut self, val: u16, interconnect: &mut Interconnect) {
+        interconnect.tick();
+        self.registers.sp -= 0x01;
+        interconnect.write_cycle_u8(self.registers.sp as u16, (val >> 0x08) as u8);
+        self.registers.sp -= 0x01;
+        interconnect.write_cycle_u8(self.registers.sp as u16, val as u8);
+    }
+
+    fn call_c_imm16(&mut self, operand: &Operand, interconnect: &mut Interconnect) -> bool {
+        let taken = self.registers.flags.carry;
+        if taken {
             let addr = operand.unwrap_imm16();
             self.call(addr, interconnect);
         }
+
+        taken
     }
 
-    fn call_nc_imm16(&mut self, operand: &Operand, interconnect: &mut Interconnect) {
-        if self.registers.flags.carry == false {
+    fn call_nc_imm16(&mut self, operand: &Operand, interconnect: &mut Interconnect) -> bool {
+        let taken = self.registers.flags.carry == false;
+        if taken {
             let addr = operand.unwrap_imm16();
             self.call(addr, interconnect);
         }
+
+        taken
     }
 
-    fn call_nz_imm16(&mut self, operand: &Operand, interconnect: &mut Interconnect) {
-        if self.registers.flags.zero == false {
+    fn call_nz_imm16(&mut self, operand: &Operand, interconnect: &mut Interconnect) -> bool {
+        let taken = self.registers.flags.zero == false;
+        if taken {
             let addr = operand.unwrap_imm16();
             self.call(addr, interconnect);
         }
+
+        taken
     }
 
-    fn call_z_imm16(&mut self, operand: &Operand, interconnect: &mut Interconnect) {
-        if self.registers.flags.zero {
+    fn call_z_imm16(&mut self, operand: &Operand, interconnect: &mut Interconnect) -> bool {
+        let taken = self.registers.flags.zero;
+        if taken {
             let addr = operand.unwrap_imm16();
             self.call(addr, interconnect);
         }
+
+        taken
     }
 
     fn ccf(&mut self) {
@@ -1438,7 +1484,7 @@ impl Cpu {
     }
 
     fn cp_hl_ptr(&mut self, interconnect: &mut Interconnect) {
-        let val = interconnect.read_u8(self.registers.get_hl());
+        let val = interconnect.read_cycle_u8(self.registers.get_hl());
         self.cp(val);
     }
 
@@ -1551,9 +1597,9 @@ impl Cpu {
     }
 
     fn dec_hl_ptr(&mut self, interconnect: &mut Interconnect) {
-        let val = interconnect.read_u8(self.registers.get_hl());
+        let val = interconnect.read_cycle_u8(self.registers.get_hl());
         let result = val.wrapping_sub(0x01);
-        interconnect.write_u8(self.registers.get_hl(), result);
+        interconnect.write_cycle_u8(self.registers.get_hl(), result);
 
         self.registers.flags.zero = result == 0x00;
         self.registers.flags.negative = true;
@@ -1574,15 +1620,34 @@ impl Cpu {
     }
 
     fn di(&mut self, interconnect: &mut Interconnect) {
+        self.ime_delay = 0x00;
         interconnect.irq.enabled = false;
     }
 
     fn ei(&mut self, interconnect: &mut Interconnect) {
-        interconnect.irq.enabled = true;
+        // A second EI doesn't push the enable back any further
+        if !interconnect.irq.enabled && self.ime_delay == 0x00 {
+            self.ime_delay = 0x02;
+        }
     }
 
-    fn halt(&mut self) {
-        self.halted = true;
+    fn halt(&mut self, interconnect: &mut Interconnect) {
+        let pending = interconnect.irq.pending();
+
+        if self.ime_delay > 0x00 && pending != 0x00 {
+            // EI right before HALT: the interrupt is taken and returns to the HALT itself
+            self.registers.pc = self.registers.pc.wrapping_sub(0x01);
+        } else if !interconnect.irq.enabled && pending != 0x00 {
+            self.halt_bug = true;
+        } else {
+            self.halted = true;
+        }
+    }
+
+    fn stop(&mut self, interconnect: &mut Interconnect) {
+        if !interconnect.stop() {
+            self.stopped = true;
+        }
     }
 
     fn inc_a(&mut self) {
@@ -1655,9 +1720,9 @@ impl Cpu {
     }
 
     fn inc_hl_ptr(&mut self, interconnect: &mut Interconnect) {
-        let val = interconnect.read_u8(self.registers.get_hl());
+        let val = interconnect.read_cycle_u8(self.registers.get_hl());
         let result = val.wrapping_add(0x01);
-        interconnect.write_u8(self.registers.get_hl(), result);
+        interconnect.write_cycle_u8(self.registers.get_hl(), result);
 
         self.registers.flags.zero = result == 0x00;
         self.registers.flags.negative = false;
@@ -1677,10 +1742,13 @@ impl Cpu {
         self.registers.sp += 0x01;
     }
 
-    fn jp_c_imm16(&mut self, operand: &Operand) {
-        if self.registers.flags.carry {
+    fn jp_c_imm16(&mut self, operand: &Operand) -> bool {
+        let taken = self.registers.flags.carry;
+        if taken {
             self.jp_imm16(operand);
         }
+
+        taken
     }
 
     fn jp_hl(&mut self) {
@@ -1697,54 +1765,75 @@ impl Cpu {
         self.registers.set_pc(addr);
     }
 
-    fn jp_nc_imm16(&mut self, operand: &Operand) {
-        if self.registers.flags.carry == false {
+    fn jp_nc_imm16(&mut self, operand: &Operand) -> bool {
+        let taken = self.registers.flags.carry == false;
+        if taken {
             self.jp_imm16(operand);
         }
+
+        taken
     }
 
-    fn jp_nz_imm16(&mut self, operand: &Operand) {
-        if !self.registers.flags.zero {
+    fn jp_nz_imm16(&mut self, operand: &Operand) -> bool {
+        let taken = !self.registers.flags.zero;
+        if taken {
             self.jp_imm16(operand);
         }
+
+        taken
     }
 
-    fn jp_z_imm16(&mut self, operand: &Operand) {
-        if self.registers.flags.zero {
+    fn jp_z_imm16(&mut self, operand: &Operand) -> bool {
+        let taken = self.registers.flags.zero;
+        if taken {
             self.jp_imm16(operand);
         }
+
+        taken
     }
 
-    fn jr_c_imm8(&mut self, operand: &Operand) {
+    fn jr_c_imm8(&mut self, operand: &Operand) -> bool {
         let offset = operand.unwrap_imm8();
 
-        if self.registers.flags.carry {
+        let taken = self.registers.flags.carry;
+        if taken {
             self.relative_jump(offset);
         }
+
+        taken
     }
 
-    fn jr_nc_imm8(&mut self, operand: &Operand) {
+    fn jr_nc_imm8(&mut self, operand: &Operand) -> bool {
         let offset = operand.unwrap_imm8();
 
-        if !self.registers.flags.carry {
+        let taken = !self.registers.flags.carry;
+        if taken {
             self.relative_jump(offset);
         }
+
+        taken
     }
 
-    fn jr_nz_imm8(&mut self, operand: &Operand) {
+    fn jr_nz_imm8(&mut self, operand: &Operand) -> bool {
         let offset = operand.unwrap_imm8();
 
-        if self.registers.flags.zero == false {
+        let taken = self.registers.flags.zero == false;
+        if taken {
             self.relative_jump(offset);
         }
+
+        taken
     }
 
-    fn jr_z_imm8(&mut self, operand: &Operand) {
+    fn jr_z_imm8(&mut self, operand: &Operand) -> bool {
         let offset = operand.unwrap_imm8();
 
-        if self.registers.flags.zero {
+        let taken = self.registers.flags.zero;
+        if taken {
             self.relative_jump(offset);
         }
+
+        taken
     }
 
     fn ld_a_b(&mut self) {
@@ -1753,7 +1842,7 @@ impl Cpu {
 
     fn ld_a_bc(&mut self, interconnect: &mut Interconnect) {
         let addr = self.registers.get_bc();
-        let val = interconnect.read_u8(addr);
+        let val = interconnect.read_cycle_u8(addr);
         self.registers.a = val;
     }
 
@@ -1762,7 +1851,7 @@ impl Cpu {
     }
 
     fn ld_a_c_ptr(&mut self, interconnect: &mut Interconnect) {
-        let val = interconnect.read_u8(0xFF00 + self.registers.c as u16);
+        let val = interconnect.read_cycle_u8(0xFF00 + self.registers.c as u16);
         self.registers.a = val;
     }
 
@@ -1772,7 +1861,7 @@ impl Cpu {
 
     fn ld_a_de(&mut self, interconnect: &mut Interconnect) {
         let addr = self.registers.get_de();
-        let val = interconnect.read_u8(addr);
+        let val = interconnect.read_cycle_u8(addr);
         self.registers.a = val;
     }
 
@@ -1783,7 +1872,7 @@ impl Cpu {
     fn ld_a_ff00_imm8(&mut self, operand: &Operand, interconnect: &mut Interconnect) {
         let offset = operand.unwrap_imm8();
         let addr = 0xFF00 as u16 + offset as u16;
-        self.registers.a = interconnect.read_u8(addr);
+        self.registers.a = interconnect.read_cycle_u8(addr);
     }
 
     fn ld_a_h(&mut self) {
@@ -1792,19 +1881,19 @@ impl Cpu {
 
     fn ld_a_hl(&mut self, interconnect: &mut Interconnect) {
         let addr = self.registers.get_hl();
-        let val = interconnect.read_u8(addr);
+        let val = interconnect.read_cycle_u8(addr);
         self.registers.a = val;
     }
 
     fn ld_a_hld(&mut self, interconnect: &mut Interconnect) {
         let addr = self.registers.get_hl();
-        self.registers.a = interconnect.read_u8(addr);
+        self.registers.a = interconnect.read_cycle_u8(addr);
         self.registers.set_hl(addr - 0x01);
     }
 
     fn ld_a_hli(&mut self, interconnect: &mut Interconnect) {
         let addr = self.registers.get_hl();
-        let val = interconnect.read_u8(addr);
+        let val = interconnect.read_cycle_u8(addr);
         self.registers.a = val;
         self.registers.set_hl(addr + 0x01);
     }
@@ -1816,7 +1905,7 @@ impl Cpu {
 
     fn ld_a_imm16(&mut self, operand: &Operand, interconnect: &mut Interconnect) {
         let addr = operand.unwrap_imm16();
-        let val = interconnect.read_u8(addr);
+        let val = interconnect.read_cycle_u8(addr);
         self.registers.a = val;
     }
 
@@ -1850,13 +1939,13 @@ impl Cpu {
 
     fn ld_b_hl_ptr(&mut self, interconnect: &mut Interconnect) {
         let addr = self.registers.get_hl();
-        let val = interconnect.read_u8(addr);
+        let val = interconnect.read_cycle_u8(addr);
         self.registers.b = val;
     }
 
     fn ld_bc_a(&mut self, interconnect: &mut Interconnect) {
         let addr = self.registers.get_bc();
-        interconnect.write_u8(addr, self.registers.a);
+        interconnect.write_cycle_u8(addr, self.registers.a);
     }
 
     fn ld_bc_imm16(&mut self, operand: &Operand) {
@@ -1895,7 +1984,7 @@ impl Cpu {
 
     fn ld_c_hl_ptr(&mut self, interconnect: &mut Interconnect) {
         let addr = self.registers.get_hl();
-        let val = interconnect.read_u8(addr);
+        let val = interconnect.read_cycle_u8(addr);
         self.registers.c = val;
     }
 
@@ -1930,7 +2019,7 @@ impl Cpu {
 
     fn ld_d_hl_ptr(&mut self, interconnect: &mut Interconnect) {
         let addr = self.registers.get_hl();
-        let val = interconnect.read_u8(addr);
+        let val = interconnect.read_cycle_u8(addr);
         self.registers.d = val;
     }
 
@@ -1941,7 +2030,7 @@ impl Cpu {
 
     fn ld_de_a(&mut self, interconnect: &mut Interconnect) {
         let addr = self.registers.get_de();
-        interconnect.write_u8(addr, self.registers.a);
+        interconnect.write_cycle_u8(addr, self.registers.a);
     }
 
     fn ld_de_imm16(&mut self, operand: &Operand) {
@@ -1971,7 +2060,7 @@ impl Cpu {
 
     fn ld_e_hl(&mut self, interconnect: &mut Interconnect) {
         let addr = self.registers.get_hl();
-        let val = interconnect.read_u8(addr);
+        let val = interconnect.read_cycle_u8(addr);
         self.registers.e = val;
     }
 
@@ -1987,12 +2076,12 @@ impl Cpu {
     fn ld_ff00_imm8_a(&mut self, operand: &Operand, interconnect: &mut Interconnect) {
         let offset = operand.unwrap_imm8();
         let addr = 0xFF00 as u16 + offset as u16;
-        interconnect.write_u8(addr, self.registers.a);
+        interconnect.write_cycle_u8(addr, self.registers.a);
     }
 
     fn ld_ff00_c_a(&mut self, interconnect: &mut Interconnect) {
         let addr = 0xFF00 as u16 + self.registers.c as u16;
-        interconnect.write_u8(addr, self.registers.a);
+        interconnect.write_cycle_u8(addr, self.registers.a);
     }
 
     fn ldhl_sp_imm8(&mut self, operand: &Operand) {
@@ -2032,7 +2121,7 @@ impl Cpu {
     }
 
     fn ld_h_hl_ptr(&mut self, interconnect: &mut Interconnect) {
-        self.registers.h = interconnect.read_u8(self.registers.get_hl());
+        self.registers.h = interconnect.read_cycle_u8(self.registers.get_hl());
     }
 
     fn ld_h_imm8(&mut self, operand: &Operand) {
@@ -2042,44 +2131,44 @@ impl Cpu {
 
     fn ld_hl_ptr_a(&mut self, interconnect: &mut Interconnect) {
         let addr = self.registers.get_hl();
-        interconnect.write_u8(addr, self.registers.a);
+        interconnect.write_cycle_u8(addr, self.registers.a);
     }
 
     fn ld_hl_ptr_b(&mut self, interconnect: &mut Interconnect) {
         let addr = self.registers.get_hl();
-        interconnect.write_u8(addr, self.registers.b);
+        interconnect.write_cycle_u8(addr, self.registers.b);
     }
 
     fn ld_hl_ptr_c(&mut self, interconnect: &mut Interconnect) {
         let addr = self.registers.get_hl();
-        interconnect.write_u8(addr, self.registers.c);
+        interconnect.write_cycle_u8(addr, self.registers.c);
     }
 
     fn ld_hl_ptr_d(&mut self, interconnect: &mut Interconnect) {
         let addr = self.registers.get_hl();
-        interconnect.write_u8(addr, self.registers.d);
+        interconnect.write_cycle_u8(addr, self.registers.d);
     }
 
     fn ld_hl_ptr_e(&mut self, interconnect: &mut Interconnect) {
         let addr = self.registers.get_hl();
-        interconnect.write_u8(addr, self.registers.e);
+        interconnect.write_cycle_u8(addr, self.registers.e);
     }
 
     fn ld_hl_ptr_h(&mut self, interconnect: &mut Interconnect) {
         let addr = self.registers.get_hl();
-        interconnect.write_u8(addr, self.registers.h);
+        interconnect.write_cycle_u8(addr, self.registers.h);
     }
 
     fn ld_hl_ptr_l(&mut self, interconnect: &mut Interconnect) {
         let addr = self.registers.get_hl();
-        interconnect.write_u8(addr, self.registers.l);
+        interconnect.write_cycle_u8(addr, self.registers.l);
     }
 
     fn ld_hl_imm8(&mut self, operand: &Operand, interconnect: &mut Interconnect) {
         let val = operand.unwrap_imm8();
         let addr = self.registers.get_hl();
 
-        interconnect.write_u8(addr, val);
+        interconnect.write_cycle_u8(addr, val);
     }
 
     fn ld_hl_imm16(&mut self, operand: &Operand) {
@@ -2089,24 +2178,24 @@ impl Cpu {
 
     fn ld_hld_a(&mut self, interconnect: &mut Interconnect) {
         let addr = self.registers.get_hl();
-        interconnect.write_u8(addr, self.registers.a);
+        interconnect.write_cycle_u8(addr, self.registers.a);
         self.registers.set_hl(addr - 0x01);
     }
 
     fn ld_hli_a(&mut self, interconnect: &mut Interconnect) {
         let addr = self.registers.get_hl();
-        interconnect.write_u8(addr, self.registers.a);
+        interconnect.write_cycle_u8(addr, self.registers.a);
         self.registers.set_hl(addr + 0x01);
     }
 
     fn ld_imm16_a(&mut self, operand: &Operand, interconnect: &mut Interconnect) {
         let addr = operand.unwrap_imm16();
-        interconnect.write_u8(addr, self.registers.a);
+        interconnect.write_cycle_u8(addr, self.registers.a);
     }
 
     fn ld_imm16_ptr_sp(&mut self, operand: &Operand, interconnect: &mut Interconnect) {
         let addr = operand.unwrap_imm16();
-        interconnect.write_u16(addr, self.registers.sp as u16);
+        interconnect.write_cycle_u16(addr, self.registers.sp as u16);
     }
 
     fn ld_l_a(&mut self) {
@@ -2135,7 +2224,7 @@ impl Cpu {
 
     fn ld_l_hl(&mut self, interconnect: &mut Interconnect) {
         let addr = self.registers.get_hl();
-        let val = interconnect.read_u8(addr);
+        let val = interconnect.read_cycle_u8(addr);
         self.registers.l = val;
     }
 
@@ -2217,7 +2306,7 @@ impl Cpu {
     }
 
     fn or_hl_ptr(&mut self, interconnect: &mut Interconnect) {
-        let val = interconnect.read_u8(self.registers.get_hl());
+        let val = interconnect.read_cycle_u8(self.registers.get_hl());
         self.registers.a |= val;
 
         self.registers.flags.zero = self.registers.a == 0x00;
@@ -2237,51 +2326,47 @@ impl Cpu {
     }
 
     fn pop_af(&mut self, interconnect: &mut Interconnect) {
-        let addr = interconnect.read_u16(self.registers.sp as u16);
+        let addr = interconnect.read_cycle_u16(self.registers.sp as u16);
         self.registers.sp += 0x02;
         self.registers.set_af(addr);
     }
 
     fn pop_bc(&mut self, interconnect: &mut Interconnect) {
-        let addr = interconnect.read_u16(self.registers.sp as u16);
+        let addr = interconnect.read_cycle_u16(self.registers.sp as u16);
         self.registers.sp += 0x02;
         self.registers.set_bc(addr);
     }
 
     fn pop_de(&mut self, interconnect: &mut Interconnect) {
-        let addr = interconnect.read_u16(self.registers.sp as u16);
+        let addr = interconnect.read_cycle_u16(self.registers.sp as u16);
         self.registers.sp += 0x02;
         self.registers.set_de(addr);
     }
 
     fn pop_hl(&mut self, interconnect: &mut Interconnect) {
-        let addr = interconnect.read_u16(self.registers.sp as u16);
+        let addr = interconnect.read_cycle_u16(self.registers.sp as u16);
         self.registers.sp += 0x02;
         self.registers.set_hl(addr);
     }
 
     fn push_af(&mut self, interconnect: &mut Interconnect) {
         let val = self.registers.get_af();
-        self.registers.sp -= 0x02;
-        interconnect.write_u16(self.registers.sp as u16, val);
+        self.push_u16(val, interconnect);
     }
 
     fn push_bc(&mut self, interconnect: &mut Interconnect) {
         let val = self.registers.get_bc();
-        self.registers.sp -= 0x02;
-        interconnect.write_u16(self.registers.sp as u16, val);
+        self.push_u16(val, interconnect);
     }
 
     fn push_de(&mut self, interconnect: &mut Interconnect) {
         let val = self.registers.get_de();
-        self.registers.sp -= 0x02;
-        interconnect.write_u16(self.registers.sp as u16, val);
+        self.push_u16(val, interconnect);
     }
 
     fn push_hl(&mut self, interconnect: &mut Interconnect) {
         let val = self.registers.get_hl();
-        self.registers.sp -= 0x02;
-        interconnect.write_u16(self.registers.sp as u16, val);
+        self.push_u16(val, interconnect);
     }
 
     fn res(&mut self, mut b: u8, n: u8) -> u8 {
@@ -2322,8 +2407,8 @@ impl Cpu {
     }
 
     fn res_0_hl_ptr(&mut self, interconnect: &mut Interconnect) {
-        let val = interconnect.read_u8(self.registers.get_hl());
-        interconnect.write_u8(self.registers.get_hl(), self.res(val, 0x00));
+        let val = interconnect.read_cycle_u8(self.registers.get_hl());
+        interconnect.write_cycle_u8(self.registers.get_hl(), self.res(val, 0x00));
     }
 
     fn res_0_l(&mut self) {
@@ -2362,8 +2447,8 @@ impl Cpu {
     }
 
     fn res_1_hl_ptr(&mut self, interconnect: &mut Interconnect) {
-        let val = interconnect.read_u8(self.registers.get_hl());
-        interconnect.write_u8(self.registers.get_hl(), self.res(val, 0x01));
+        let val = interconnect.read_cycle_u8(self.registers.get_hl());
+        interconnect.write_cycle_u8(self.registers.get_hl(), self.res(val, 0x01));
     }
 
     fn res_1_l(&mut self) {
@@ -2402,8 +2487,8 @@ impl Cpu {
     }
 
     fn res_2_hl_ptr(&mut self, interconnect: &mut Interconnect) {
-        let val = interconnect.read_u8(self.registers.get_hl());
-        interconnect.write_u8(self.registers.get_hl(), self.res(val, 0x02));
+        let val = interconnect.read_cycle_u8(self.registers.get_hl());
+        interconnect.write_cycle_u8(self.registers.get_hl(), self.res(val, 0x02));
     }
 
     fn res_2_l(&mut self) {
@@ -2442,8 +2527,8 @@ impl Cpu {
     }
 
     fn res_3_hl_ptr(&mut self, interconnect: &mut Interconnect) {
-        let val = interconnect.read_u8(self.registers.get_hl());
-        interconnect.write_u8(self.registers.get_hl(), self.res(val, 0x03));
+        let val = interconnect.read_cycle_u8(self.registers.get_hl());
+        interconnect.write_cycle_u8(self.registers.get_hl(), self.res(val, 0x03));
     }
 
     fn res_3_l(&mut self) {
@@ -2482,8 +2567,8 @@ impl Cpu {
     }
 
     fn res_4_hl_ptr(&mut self, interconnect: &mut Interconnect) {
-        let val = interconnect.read_u8(self.registers.get_hl());
-        interconnect.write_u8(self.registers.get_hl(), self.res(val, 0x04));
+        let val = interconnect.read_cycle_u8(self.registers.get_hl());
+        interconnect.write_cycle_u8(self.registers.get_hl(), self.res(val, 0x04));
     }
 
     fn res_4_l(&mut self) {
@@ -2522,8 +2607,8 @@ impl Cpu {
     }
 
     fn res_5_hl_ptr(&mut self, interconnect: &mut Interconnect) {
-        let val = interconnect.read_u8(self.registers.get_hl());
-        interconnect.write_u8(self.registers.get_hl(), self.res(val, 0x05));
+        let val = interconnect.read_cycle_u8(self.registers.get_hl());
+        interconnect.write_cycle_u8(self.registers.get_hl(), self.res(val, 0x05));
     }
 
     fn res_5_l(&mut self) {
@@ -2562,8 +2647,8 @@ impl Cpu {
     }
 
     fn res_6_hl_ptr(&mut self, interconnect: &mut Interconnect) {
-        let val = interconnect.read_u8(self.registers.get_hl());
-        interconnect.write_u8(self.registers.get_hl(), self.res(val, 0x06));
+        let val = interconnect.read_cycle_u8(self.registers.get_hl());
+        interconnect.write_cycle_u8(self.registers.get_hl(), self.res(val, 0x06));
     }
 
     fn res_6_l(&mut self) {
@@ -2602,8 +2687,8 @@ impl Cpu {
     }
 
     fn res_7_hl_ptr(&mut self, interconnect: &mut Interconnect) {
-        let val = interconnect.read_u8(self.registers.get_hl());
-        interconnect.write_u8(self.registers.get_hl(), self.res(val, 0x07));
+        let val = interconnect.read_cycle_u8(self.registers.get_hl());
+        interconnect.write_cycle_u8(self.registers.get_hl(), self.res(val, 0x07));
     }
 
     fn res_7_l(&mut self) {
@@ -2612,49 +2697,73 @@ impl Cpu {
     }
 
     fn ret(&mut self, interconnect: &mut Interconnect) {
-        let addr = interconnect.read_u16(self.registers.sp as u16);
+        let addr = interconnect.read_cycle_u16(self.registers.sp as u16);
         self.registers.sp += 0x02;
         self.registers.pc = addr;
     }
 
     fn reti(&mut self, interconnect: &mut Interconnect) {
-        let addr = interconnect.read_u16(self.registers.sp as u16);
+        let addr = interconnect.read_cycle_u16(self.registers.sp as u16);
         self.registers.sp += 0x02;
         self.registers.pc = addr;
 
         interconnect.irq.enabled = true;
     }
 
-    fn ret_c(&mut self, interconnect: &mut Interconnect) {
-        if self.registers.flags.carry {
-            let addr = interconnect.read_u16(self.registers.sp as u16);
+    fn ret_c(&mut self, interconnect: &mut Interconnect) -> bool {
+        // Checking the condition takes an M-cycle of its own
+        interconnect.tick();
+
+        let taken = self.registers.flags.carry;
+        if taken {
+            let addr = interconnect.read_cycle_u16(self.registers.sp as u16);
             self.registers.sp += 0x02;
             self.registers.pc = addr;
         }
+
+        taken
     }
 
-    fn ret_nc(&mut self, interconnect: &mut Interconnect) {
-        if self.registers.flags.carry == false {
-            let addr = interconnect.read_u16(self.registers.sp as u16);
+    fn ret_nc(&mut self, interconnect: &mut Interconnect) -> bool {
+        // Checking the condition takes an M-cycle of its own
+        interconnect.tick();
+
+        let taken = self.registers.flags.carry == false;
+        if taken {
+            let addr = interconnect.read_cycle_u16(self.registers.sp as u16);
             self.registers.sp += 0x02;
             self.registers.pc = addr;
         }
+
+        taken
     }
 
-    fn ret_nz(&mut self, interconnect: &mut Interconnect) {
-        if self.registers.flags.zero == false {
-            let addr = interconnect.read_u16(self.registers.sp as u16);
+    fn ret_nz(&mut self, interconnect: &mut Interconnect) -> bool {
+        // Checking the condition takes an M-cycle of its own
+        interconnect.tick();
+
+        let taken = self.registers.flags.zero == false;
+        if taken {
+            let addr = interconnect.read_cycle_u16(self.registers.sp as u16);
             self.registers.sp += 0x02;
             self.registers.pc = addr;
         }
+
+        taken
     }
 
-    fn ret_z(&mut self, interconnect: &mut Interconnect) {
-        if self.registers.flags.zero {
-            let addr = interconnect.read_u16(self.registers.sp as u16);
+    fn ret_z(&mut self, interconnect: &mut Interconnect) -> bool {
+        // Checking the condition takes an M-cycle of its own
+        interconnect.tick();
+
+        let taken = self.registers.flags.zero;
+        if taken {
+            let addr = interconnect.read_cycle_u16(self.registers.sp as u16);
             self.registers.sp += 0x02;
             self.registers.pc = addr;
         }
+
+        taken
     }
 
     fn rla(&mut self) {
@@ -2718,8 +2827,8 @@ impl Cpu {
     }
 
     fn rl_hl_ptr(&mut self, interconnect: &mut Interconnect) {
-        let val = interconnect.read_u8(self.registers.get_hl());
-        interconnect.write_u8(self.registers.get_hl(), self.rl(val));
+        let val = interconnect.read_cycle_u8(self.registers.get_hl());
+        interconnect.write_cycle_u8(self.registers.get_hl(), self.rl(val));
     }
 
     fn rl_l(&mut self) {
@@ -2770,8 +2879,8 @@ impl Cpu {
     }
 
     fn rlc_hl_ptr(&mut self, interconnect: &mut Interconnect) {
-        let val = interconnect.read_u8(self.registers.get_hl());
-        interconnect.write_u8(self.registers.get_hl(), self.rlc(val));
+        let val = interconnect.read_cycle_u8(self.registers.get_hl());
+        interconnect.write_cycle_u8(self.registers.get_hl(), self.rlc(val));
     }
 
     fn rlc_l(&mut self) {
@@ -2832,8 +2941,8 @@ impl Cpu {
     }
 
     fn rr_hl_ptr(&mut self, interconnect: &mut Interconnect) {
-        let val = interconnect.read_u8(self.registers.get_hl());
-        interconnect.write_u8(self.registers.get_hl(), self.rr(val));
+        let val = interconnect.read_cycle_u8(self.registers.get_hl());
+        interconnect.write_cycle_u8(self.registers.get_hl(), self.rr(val));
     }
 
     fn rr_l(&mut self) {
@@ -2890,8 +2999,8 @@ impl Cpu {
     }
 
     fn rrc_hl_ptr(&mut self, interconnect: &mut Interconnect) {
-        let val = interconnect.read_u8(self.registers.get_hl());
-        interconnect.write_u8(self.registers.get_hl(), self.rrc(val));
+        let val = interconnect.read_cycle_u8(self.registers.get_hl());
+        interconnect.write_cycle_u8(self.registers.get_hl(), self.rrc(val));
     }
 
     fn rrc_l(&mut self) {
@@ -2959,7 +3068,7 @@ impl Cpu {
     }
 
     fn sbc_a_hl_ptr(&mut self, interconnect: &mut Interconnect) {
-        let val = interconnect.read_u8(self.registers.get_hl());
+        let val = interconnect.read_cycle_u8(self.registers.get_hl());
         self.sbc(val);
     }
 
@@ -3012,8 +3121,8 @@ impl Cpu {
     }
 
     fn set_0_hl_ptr(&mut self, interconnect: &mut Interconnect) {
-        let val = interconnect.read_u8(self.registers.get_hl());
-        interconnect.write_u8(self.registers.get_hl(), self.set(val, 0x00));
+        let val = interconnect.read_cycle_u8(self.registers.get_hl());
+        interconnect.write_cycle_u8(self.registers.get_hl(), self.set(val, 0x00));
     }
 
     fn set_0_l(&mut self) {
@@ -3052,8 +3161,8 @@ impl Cpu {
     }
 
     fn set_1_hl_ptr(&mut self, interconnect: &mut Interconnect) {
-        let val = interconnect.read_u8(self.registers.get_hl());
-        interconnect.write_u8(self.registers.get_hl(), self.set(val, 0x01));
+        let val = interconnect.read_cycle_u8(self.registers.get_hl());
+        interconnect.write_cycle_u8(self.registers.get_hl(), self.set(val, 0x01));
     }
 
     fn set_1_l(&mut self) {
@@ -3092,8 +3201,8 @@ impl Cpu {
     }
 
     fn set_2_hl_ptr(&mut self, interconnect: &mut Interconnect) {
-        let val = interconnect.read_u8(self.registers.get_hl());
-        interconnect.write_u8(self.registers.get_hl(), self.set(val, 0x02));
+        let val = interconnect.read_cycle_u8(self.registers.get_hl());
+        interconnect.write_cycle_u8(self.registers.get_hl(), self.set(val, 0x02));
     }
 
     fn set_2_l(&mut self) {
@@ -3132,8 +3241,8 @@ impl Cpu {
     }
 
     fn set_3_hl_ptr(&mut self, interconnect: &mut Interconnect) {
-        let val = interconnect.read_u8(self.registers.get_hl());
-        interconnect.write_u8(self.registers.get_hl(), self.set(val, 0x03));
+        let val = interconnect.read_cycle_u8(self.registers.get_hl());
+        interconnect.write_cycle_u8(self.registers.get_hl(), self.set(val, 0x03));
     }
 
     fn set_3_l(&mut self) {
@@ -3172,8 +3281,8 @@ impl Cpu {
     }
 
     fn set_4_hl_ptr(&mut self, interconnect: &mut Interconnect) {
-        let val = interconnect.read_u8(self.registers.get_hl());
-        interconnect.write_u8(self.registers.get_hl(), self.set(val, 0x04));
+        let val = interconnect.read_cycle_u8(self.registers.get_hl());
+        interconnect.write_cycle_u8(self.registers.get_hl(), self.set(val, 0x04));
     }
 
     fn set_4_l(&mut self) {
@@ -3212,8 +3321,8 @@ impl Cpu {
     }
 
     fn set_5_hl_ptr(&mut self, interconnect: &mut Interconnect) {
-        let val = interconnect.read_u8(self.registers.get_hl());
-        interconnect.write_u8(self.registers.get_hl(), self.set(val, 0x05));
+        let val = interconnect.read_cycle_u8(self.registers.get_hl());
+        interconnect.write_cycle_u8(self.registers.get_hl(), self.set(val, 0x05));
     }
 
     fn set_5_l(&mut self) {
@@ -3252,8 +3361,8 @@ impl Cpu {
     }
 
     fn set_6_hl_ptr(&mut self, interconnect: &mut Interconnect) {
-        let val = interconnect.read_u8(self.registers.get_hl());
-        interconnect.write_u8(self.registers.get_hl(), self.set(val, 0x06));
+        let val = interconnect.read_cycle_u8(self.registers.get_hl());
+        interconnect.write_cycle_u8(self.registers.get_hl(), self.set(val, 0x06));
     }
 
     fn set_6_l(&mut self) {
@@ -3292,8 +3401,8 @@ impl Cpu {
     }
 
     fn set_7_hl_ptr(&mut self, interconnect: &mut Interconnect) {
-        let val = interconnect.read_u8(self.registers.get_hl());
-        interconnect.write_u8(self.registers.get_hl(), self.set(val, 0x07));
+        let val = interconnect.read_cycle_u8(self.registers.get_hl());
+        interconnect.write_cycle_u8(self.registers.get_hl(), self.set(val, 0x07));
     }
 
     fn set_7_l(&mut self) {
@@ -3344,8 +3453,8 @@ impl Cpu {
     }
 
     fn sla_hl_ptr(&mut self, interconnect: &mut Interconnect) {
-        let val = interconnect.read_u8(self.registers.get_hl());
-        interconnect.write_u8(self.registers.get_hl(), self.sla(val));
+        let val = interconnect.read_cycle_u8(self.registers.get_hl());
+        interconnect.write_cycle_u8(self.registers.get_hl(), self.sla(val));
     }
 
     fn sla_l(&mut self) {
@@ -3396,8 +3505,8 @@ impl Cpu {
     }
 
     fn sra_hl_ptr(&mut self, interconnect: &mut Interconnect) {
-        let val = interconnect.read_u8(self.registers.get_hl());
-        interconnect.write_u8(self.registers.get_hl(), self.sra(val));
+        let val = interconnect.read_cycle_u8(self.registers.get_hl());
+        interconnect.write_cycle_u8(self.registers.get_hl(), self.sra(val));
     }
 
     fn sra_l(&mut self) {
@@ -3448,8 +3557,8 @@ impl Cpu {
     }
 
     fn srl_hl_ptr(&mut self, interconnect: &mut Interconnect) {
-        let val = interconnect.read_u8(self.registers.get_hl());
-        interconnect.write_u8(self.registers.get_hl(), self.srl(val));
+        let val = interconnect.read_cycle_u8(self.registers.get_hl());
+        interconnect.write_cycle_u8(self.registers.get_hl(), self.srl(val));
     }
 
     fn srl_l(&mut self) {
@@ -3504,7 +3613,7 @@ impl Cpu {
     }
 
     fn sub_hl(&mut self, interconnect: &mut Interconnect) {
-        let val = interconnect.read_u8(self.registers.get_hl());
+        let val = interconnect.read_cycle_u8(self.registers.get_hl());
         self.sub(val);
     }
 
@@ -3555,8 +3664,8 @@ impl Cpu {
     }
 
     fn swap_hl_ptr(&mut self, interconnect: &mut Interconnect) {
-        let val = interconnect.read_u8(self.registers.get_hl());
-        interconnect.write_u8(self.registers.get_hl(), self.swap(val));
+        let val = interconnect.read_cycle_u8(self.registers.get_hl());
+        interconnect.write_cycle_u8(self.registers.get_hl(), self.swap(val));
     }
 
     fn swap_l(&mut self) {
@@ -3603,7 +3712,7 @@ impl Cpu {
     }
 
     fn xor_hl_ptr(&mut self, interconnect: &mut Interconnect) {
-        let val = interconnect.read_u8(self.registers.get_hl());
+        let val = interconnect.read_cycle_u8(self.registers.get_hl());
         self.xor(val);
     }
 
@@ -3627,3 +3736,266 @@ impl Cpu {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use gameboy::{GameBoy, HardwareModel, Interrupt, JoypadButton, M_CYCLE};
+    use gameboy::opcodes::OpCode;
+
+    // M-cycles per opcode as documented in blargg's instr_timing tables. Conditional branches
+    // are listed as not taken. 0 marks opcodes that don't exist, and 0xCB is timed on its own.
+    const DOCUMENTED_CYCLES: [u8; 256] = [
+        1, 3, 2, 2, 1, 1, 2, 1, 5, 2, 2, 2, 1, 1, 2, 1, // 0x00
+        1, 3, 2, 2, 1, 1, 2, 1, 3, 2, 2, 2, 1, 1, 2, 1, // 0x10
+        2, 3, 2, 2, 1, 1, 2, 1, 2, 2, 2, 2, 1, 1, 2, 1, // 0x20
+        2, 3, 2, 2, 3, 3, 3, 1, 2, 2, 2, 2, 1, 1, 2, 1, // 0x30
+        1, 1, 1, 1, 1, 1, 2, 1, 1, 1, 1, 1, 1, 1, 2, 1, // 0x40
+        1, 1, 1, 1, 1, 1, 2, 1, 1, 1, 1, 1, 1, 1, 2, 1, // 0x50
+        1, 1, 1, 1, 1, 1, 2, 1, 1, 1, 1, 1, 1, 1, 2, 1, // 0x60
+        2, 2, 2, 2, 2, 2, 1, 2, 1, 1, 1, 1, 1, 1, 2, 1, // 0x70
+        1, 1, 1, 1, 1, 1, 2, 1, 1, 1, 1, 1, 1, 1, 2, 1, // 0x80
+        1, 1, 1, 1, 1, 1, 2, 1, 1, 1, 1, 1, 1, 1, 2, 1, // 0x90
+        1, 1, 1, 1, 1, 1, 2, 1, 1, 1, 1, 1, 1, 1, 2, 1, // 0xA0
+        1, 1, 1, 1, 1, 1, 2, 1, 1, 1, 1, 1, 1, 1, 2, 1, // 0xB0
+        2, 3, 3, 4, 3, 4, 2, 4, 2, 4, 3, 0, 3, 6, 2, 4, // 0xC0
+        2, 3, 3, 0, 3, 4, 2, 4, 2, 4, 3, 0, 3, 0, 2, 4, // 0xD0
+        3, 3, 2, 0, 0, 4, 2, 4, 4, 1, 4, 0, 0, 0, 2, 4, // 0xE0
+        3, 3, 2, 1, 0, 4, 2, 4, 3, 2, 4, 1, 0, 0, 2, 4, // 0xF0
+    ];
+
+    // JR cc, RET cc, JP cc and CALL cc when the condition holds
+    fn documented_taken_cycles(code: u8) -> Option<u8> {
+        match code {
+            0x20 | 0x28 | 0x30 | 0x38 => Some(3),
+            0xC0 | 0xC8 | 0xD0 | 0xD8 => Some(5),
+            0xC2 | 0xCA | 0xD2 | 0xDA => Some(4),
+            0xC4 | 0xCC | 0xD4 | 0xDC => Some(6),
+            _ => None,
+        }
+    }
+
+    // Every CB opcode takes 2 M-cycles, or 4 on (HL). BIT only reads (HL), so it takes 3.
+    fn documented_ext_cycles(code: u8) -> u8 {
+        match (code & 0x07, code & 0xC0) {
+            (0x06, 0x40) => 3,
+            (0x06, _) => 4,
+            _ => 2,
+        }
+    }
+
+    // Runs `program` from WRAM with every flag set to `flags`, returning the cycles
+    // reported for its first instruction
+    fn time(gb: &mut GameBoy, program: &[u8], flags: bool) -> usize {
+        for (i, b) in program.iter().enumerate() {
+            gb.interconnect.write_u8(0xC000 + i as u16, *b);
+        }
+
+        gb.cpu.halted = false;
+        gb.cpu.stopped = false;
+        gb.cpu.ime_delay = 0x00;
+        gb.interconnect.irq.enabled = false;
+
+        gb.cpu.registers.set_bc(0xC180);
+        gb.cpu.registers.set_de(0xC200);
+        gb.cpu.registers.set_hl(0xC100);
+        gb.cpu.registers.sp = 0xDFF0;
+        gb.cpu.registers.pc = 0xC000;
+        gb.cpu.registers.flags.zero = flags;
+        gb.cpu.registers.flags.negative = flags;
+        gb.cpu.registers.flags.half_carry = flags;
+        gb.cpu.registers.flags.carry = flags;
+
+        gb.cpu.step(&mut gb.interconnect).unwrap() as usize
+    }
+
+    #[test]
+    fn opcode_cycles_match_documented_timings() {
+        let mut gb = GameBoy::with_program(HardwareModel::DMG, &[]);
+
+        for code in 0..0x100 {
+            let code = code as u8;
+            if code == 0xCB || OpCode::from_byte(code, false).is_none() {
+                continue;
+            }
+
+            for &flags in &[false, true] {
+                // Conditions are NZ, Z, NC and C, so one of the two flag states takes them
+                let taken = match (code >> 0x03) & 0x03 {
+                    0x00 | 0x02 => !flags,
+                    _ => flags,
+                };
+                let expected = match documented_taken_cycles(code) {
+                    Some(cycles) if taken => cycles,
+                    _ => DOCUMENTED_CYCLES[code as usize],
+                };
+
+                assert_eq!(
+                    time(&mut gb, &[code, 0x00, 0x00], flags),
+                    expected as usize * M_CYCLE,
+                    "opcode 0x{:02X}, flags {}",
+                    code,
+                    flags
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn ext_opcode_cycles_match_documented_timings() {
+        let mut gb = GameBoy::with_program(HardwareModel::DMG, &[]);
+
+        for code in 0..0x100 {
+            let code = code as u8;
+            assert_eq!(
+                time(&mut gb, &[0xCB, code], false),
+                documented_ext_cycles(code) as usize * M_CYCLE,
+                "opcode 0xCB 0x{:02X}",
+                code
+            );
+        }
+    }
+
+    // Steps one instruction at a time, servicing interrupts in between as `cycle` does
+    fn run(gb: &mut GameBoy, instructions: usize) {
+        for _ in 0..instructions {
+            gb.cpu.step(&mut gb.interconnect).unwrap();
+            gb.cpu.handle_interrupts(&mut gb.interconnect);
+        }
+    }
+
+    // DI; LD A,0x04; LDH (IE),A; LDH (IF),A, leaving a timer interrupt pending with IME off
+    fn pending_timer_interrupt(program: Vec<u8>) -> GameBoy {
+        let mut rom = gb_asm![0xF3 0x3E 0x04 0xE0 0xFF 0xE0 0x0F];
+        rom.extend(program);
+
+        let mut gb = GameBoy::with_program(HardwareModel::DMG, &rom);
+        run(&mut gb, 0x04);
+        gb
+    }
+
+    #[test]
+    fn ei_takes_effect_after_the_next_instruction() {
+        // EI; NOP; INC B
+        let mut gb = pending_timer_interrupt(gb_asm![0xFB 0x00 0x04]);
+
+        run(&mut gb, 0x01);
+        assert_eq!(gb.cpu.registers.pc, 0x158);
+
+        run(&mut gb, 0x01);
+        assert_eq!(gb.cpu.registers.pc, 0x50);
+        assert_eq!(gb.interconnect.read_u16(gb.cpu.registers.sp as u16), 0x159);
+    }
+
+    #[test]
+    fn ei_then_di_never_dispatches() {
+        // EI; DI; INC B; INC B
+        let mut gb = pending_timer_interrupt(gb_asm![0xFB 0xF3 0x04 0x04]);
+        let b = gb.cpu.registers.b;
+
+        run(&mut gb, 0x04);
+        assert_eq!(gb.cpu.registers.pc, 0x15B);
+        assert_eq!(gb.cpu.registers.b, b.wrapping_add(0x02));
+        assert_eq!(gb.interconnect.irq.pending(), 0x04);
+    }
+
+    #[test]
+    fn halt_bug_executes_the_next_byte_twice() {
+        // HALT; INC B; NOP
+        let mut gb = pending_timer_interrupt(gb_asm![0x76 0x04 0x00]);
+        let b = gb.cpu.registers.b;
+
+        run(&mut gb, 0x03);
+        assert!(!gb.cpu.halted);
+        assert_eq!(gb.cpu.registers.b, b.wrapping_add(0x02));
+        assert_eq!(gb.cpu.registers.pc, 0x159);
+    }
+
+    #[test]
+    fn ei_then_halt_returns_to_the_halt() {
+        // EI; HALT; INC B
+        let mut gb = pending_timer_interrupt(gb_asm![0xFB 0x76 0x04]);
+
+        run(&mut gb, 0x02);
+        assert!(!gb.cpu.halted);
+        assert_eq!(gb.cpu.registers.pc, 0x50);
+        assert_eq!(gb.interconnect.read_u16(gb.cpu.registers.sp as u16), 0x158);
+    }
+
+    #[test]
+    fn stop_waits_for_a_button() {
+        // XOR A; LDH (P1),A; STOP; INC B
+        let program = gb_asm![0xAF 0xE0 0x00 0x10 0x00 0x04];
+        let mut gb = GameBoy::with_program(HardwareModel::DMG, &program);
+        let b = gb.cpu.registers.b;
+
+        run(&mut gb, 0x03);
+        assert!(gb.cpu.stopped);
+
+        run(&mut gb, 0x10);
+        assert!(gb.cpu.stopped);
+        assert_eq!(gb.cpu.registers.b, b);
+
+        gb.press(JoypadButton::Down);
+        run(&mut gb, 0x02);
+        assert!(!gb.cpu.stopped);
+        assert_eq!(gb.cpu.registers.b, b.wrapping_add(0x01));
+    }
+
+    #[test]
+    fn stop_switches_speed_when_armed() {
+        // LD A,0x01; LDH (KEY1),A; STOP; INC B
+        let program = gb_asm![0x3E 0x01 0xE0 0x4D 0x10 0x00 0x04];
+        let mut gb = GameBoy::with_program(HardwareModel::CGB, &program);
+
+        run(&mut gb, 0x03);
+        assert!(!gb.cpu.stopped);
+        assert!(gb.interconnect.double_speed);
+    }
+
+    #[test]
+    fn interrupt_dispatch_takes_five_m_cycles() {
+        // EI; NOP; NOP
+        let mut gb = pending_timer_interrupt(gb_asm![0xFB 0x00 0x00]);
+
+        gb.cpu.step(&mut gb.interconnect).unwrap();
+        gb.cpu.step(&mut gb.interconnect).unwrap();
+
+        assert_eq!(gb.cpu.handle_interrupts(&mut gb.interconnect), 0x14);
+        assert_eq!(gb.interconnect.take_cycles().unwrap(), 0x14);
+        assert_eq!(gb.cpu.registers.pc, 0x50);
+        assert_eq!(gb.interconnect.irq.pending(), 0x00);
+    }
+
+    #[test]
+    fn pushing_over_ie_cancels_dispatch() {
+        // LD SP,0x0000; LD A,0x04; LDH (IE),A; LDH (IF),A; EI; NOP. The high byte of PC lands
+        // on IE, which no longer enables the timer interrupt by the time one is picked.
+        let program = gb_asm![0x31 0x00 0x00 0x3E 0x04 0xE0 0xFF 0xE0 0x0F 0xFB 0x00];
+        let mut gb = GameBoy::with_program(HardwareModel::DMG, &program);
+
+        run(&mut gb, 0x06);
+        assert_eq!(gb.cpu.registers.pc, 0x0000);
+        assert_eq!(gb.interconnect.irq.enable_flag, 0x01);
+        assert_eq!(gb.interconnect.irq.request_flag & 0x04, 0x04);
+    }
+
+    #[test]
+    fn halt_wakes_without_dispatch_when_ime_is_off() {
+        // DI; LD A,0x04; LDH (IE),A; HALT; INC B
+        let program = gb_asm![0xF3 0x3E 0x04 0xE0 0xFF 0x76 0x04];
+        let mut gb = GameBoy::with_program(HardwareModel::DMG, &program);
+        let b = gb.cpu.registers.b;
+
+        run(&mut gb, 0x04);
+        assert!(gb.cpu.halted);
+
+        run(&mut gb, 0x04);
+        assert!(gb.cpu.halted);
+
+        gb.interconnect.irq.request(Interrupt::Timer);
+        run(&mut gb, 0x02);
+        assert!(!gb.cpu.halted);
+        assert_eq!(gb.cpu.registers.b, b.wrapping_add(0x01));
+        assert_eq!(gb.cpu.registers.pc, 0x157);
+    }
+}
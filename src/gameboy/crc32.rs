@@ -0,0 +1,20 @@
+// CRC-32 (IEEE 802.3), as used by UPS/BPS patches and ROM databases
+
+const POLYNOMIAL: u32 = 0xEDB88320;
+
+pub fn crc32(data: &[u8]) -> u32 {
+    let mut crc = 0xFFFFFFFF;
+
+    for b in data {
+        crc ^= *b as u32;
+        for _ in 0..8 {
+            crc = if crc & 0x01 == 0x01 {
+                (crc >> 0x01) ^ POLYNOMIAL
+            } else {
+                crc >> 0x01
+            };
+        }
+    }
+
+    !crc
+}
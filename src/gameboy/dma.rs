@@ -0,0 +1,51 @@
+// OAM DMA - copies 0xA0 bytes from (source << 8) into OAM, one byte per M-cycle.
+// The copy starts one M-cycle after the write to 0xFF46 and OAM can't be used by
+// the CPU until it finishes.
+
+const OAM_SIZE: u16 = 0xA0;
+
+pub struct Dma {
+    // Last value written to 0xFF46, which reads back as-is
+    pub source: u8,
+
+    starting: bool,
+    // None when no transfer is running, otherwise the next byte to copy
+    progress: Option<u16>,
+}
+
+impl Dma {
+    pub fn new() -> Dma {
+        Dma {
+            source: 0xFF,
+            starting: false,
+            progress: None,
+        }
+    }
+
+    // Writing again mid-transfer restarts it from the new source
+    pub fn start(&mut self, source: u8) {
+        self.source = source;
+        self.starting = true;
+    }
+
+    pub fn blocking_oam(&self) -> bool {
+        self.progress.is_some()
+    }
+
+    // Advances one M-cycle, returning the source address and OAM offset of the byte to copy
+    pub fn tick(&mut self) -> Option<(u16, u16)> {
+        let copy = self.progress.map(|offset| (((self.source as u16) << 0x08) + offset, offset));
+
+        self.progress = match self.progress {
+            Some(offset) if offset + 0x01 < OAM_SIZE => Some(offset + 0x01),
+            _ => None,
+        };
+
+        if self.starting {
+            self.starting = false;
+            self.progress = Some(0x00);
+        }
+
+        copy
+    }
+}
@@ -1,7 +1,14 @@
-use gameboy::cartridge::{Cartridge, CartridgeDetails};
+use std::io;
+use std::path::Path;
+
+use gameboy::cartridge::Cartridge;
+use gameboy::cartridge_details::CartridgeDetails;
+use gameboy::cheat::{Cheat, CheatError};
 use gameboy::cpu;
-use ::gameboy::gfx::Frame;
+use gameboy::hardware_model::HardwareModel;
+use ::gameboy::gfx::{Frame, Renderer};
 use gameboy::joypad::JoypadButton;
+use gameboy::mbc::{CameraSource, IrTransceiver};
 use gameboy::interconnect::Interconnect;
 use gameboy::ui::theme::Theme;
 
@@ -11,16 +18,18 @@ pub struct GameBoy {
 }
 
 impl GameBoy {
-    pub fn new(gameboy_color: bool, cart: Cartridge, disable_boot_rom: bool) -> GameBoy {
+    // The boot ROM only runs on models we have one for, the rest start at 0x0100 regardless
+    pub fn new(model: HardwareModel, cart: Cartridge, boot_rom_enabled: bool) -> GameBoy {
         let mut gb = GameBoy {
-            cpu: cpu::Cpu::new(gameboy_color),
-            interconnect: Interconnect::with_cart(cart, disable_boot_rom),
+            cpu: cpu::Cpu::new(),
+            interconnect: Interconnect::with_cart(cart, model, boot_rom_enabled),
         };
         gb.reset();
         gb
     }
 
     pub fn run(&mut self) -> Result<(), String> {
+        self.interconnect.apply_cheats();
         self.cpu.cycle(&mut self.interconnect)
     }
 
@@ -29,6 +38,10 @@ impl GameBoy {
         self.cpu.reset(&mut self.interconnect);
     }
 
+    pub fn model(&self) -> HardwareModel {
+        self.interconnect.model
+    }
+
     pub fn cart_details(&self) -> &CartridgeDetails {
         self.interconnect.cart_details()
     }
@@ -37,6 +50,56 @@ impl GameBoy {
         self.interconnect.request_frame()
     }
 
+    // Adds a Game Genie or GameShark cheat, enabled, and returns its id
+    pub fn add_cheat<S>(&mut self, name: S, code: &str) -> Result<usize, CheatError>
+        where S: Into<String>
+    {
+        self.interconnect.cheats.add(name, code)
+    }
+
+    pub fn load_cheat_file<P>(&mut self, p: P) -> Result<usize, CheatError>
+        where P: AsRef<Path>
+    {
+        self.interconnect.cheats.load_file(p)
+    }
+
+    pub fn enable_cheat(&mut self, id: usize) -> Result<(), CheatError> {
+        self.interconnect.cheats.set_enabled(id, true)
+    }
+
+    pub fn disable_cheat(&mut self, id: usize) -> Result<(), CheatError> {
+        self.interconnect.cheats.set_enabled(id, false)
+    }
+
+    pub fn cheats(&self) -> &[Cheat] {
+        self.interconnect.cheats.list()
+    }
+
+    // Persists battery-backed cartridge RAM, if a save file is attached
+    pub fn save(&mut self) -> io::Result<()> {
+        self.interconnect.flush_save_file()
+    }
+
+    // True while a rumble cartridge has its motor switched on
+    pub fn rumble(&self) -> bool {
+        self.interconnect.rumble()
+    }
+
+    // Feeds the accelerometer on tilt-sensing cartridges. Each axis is in g, from -1.0 to 1.0.
+    pub fn set_tilt(&mut self, x: f32, y: f32) {
+        self.interconnect.set_tilt(x, y);
+    }
+
+    // Connects the infrared port on HuC1/HuC3 cartridges to something, e.g. a loopback
+    pub fn set_ir_transceiver(&mut self, transceiver: Box<IrTransceiver>) {
+        self.interconnect.set_ir_transceiver(transceiver);
+    }
+
+    // Points the Game Boy Camera at something other than a blank image
+    pub fn set_camera_source(&mut self, source: Box<CameraSource>) {
+        self.interconnect.set_camera_source(source);
+    }
+
     pub fn press(&mut self, button: JoypadButton) {
         self.interconnect.press(button);
     }
@@ -49,7 +112,97 @@ impl GameBoy {
         self.cpu.speed = speed;
     }
 
+    pub fn set_renderer(&mut self, renderer: Renderer) {
+        self.interconnect.gpu.renderer = renderer;
+    }
+
     pub fn switch_theme(&mut self, theme: Theme) {
         self.interconnect.gpu.theme = theme;
     }
 }
+
+#[cfg(test)]
+impl GameBoy {
+    // A ROM-only cartridge that jumps straight to `program` at 0x0150, past the header
+    pub fn test_rom(program: &[u8]) -> Vec<u8> {
+        let mut rom = vec![0x00; 0x8000];
+        rom[0x100..0x104].copy_from_slice(&[0x00, 0xC3, 0x50, 0x01]);
+        rom[0x150..0x150 + program.len()].copy_from_slice(program);
+        rom
+    }
+
+    // Skips the boot ROM and runs `rom` up to 0x0150, the start of the program in `test_rom`
+    pub fn with_rom(model: HardwareModel, rom: &[u8]) -> GameBoy {
+        let cart = Cartridge::load(rom, true).expect("test ROM failed to load");
+        let mut gb = GameBoy::new(model, cart, false);
+        while gb.cpu.registers.pc != 0x150 {
+            gb.cpu.step(&mut gb.interconnect).unwrap();
+        }
+        gb
+    }
+
+    pub fn with_program(model: HardwareModel, program: &[u8]) -> GameBoy {
+        GameBoy::with_rom(model, &GameBoy::test_rom(program))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+    use std::io::Read;
+    use std::path::Path;
+
+    use image;
+
+    use gameboy::{Cartridge, GameBoy, HardwareModel, Renderer, SCREEN_WIDTH};
+
+    // Shades are compared rather than exact colors, so any greyscale palette will do
+    fn shade(luma: u8) -> u8 {
+        match luma {
+            0xD5...0xFF => 0x00,
+            0x80...0xD4 => 0x01,
+            0x2B...0x7F => 0x02,
+            _ => 0x03,
+        }
+    }
+
+    // dmg-acid2 (https://github.com/mattcurrie/dmg-acid2, MIT licensed) goes in
+    // test-roms/dmg-acid2/, as dmg-acid2.gb next to its img/reference-dmg.png. Run with
+    // `cargo test -- --ignored` once it's there.
+    #[test]
+    #[ignore]
+    fn dmg_acid2_matches_the_reference() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("test-roms/dmg-acid2");
+
+        let mut rom = Vec::new();
+        File::open(dir.join("dmg-acid2.gb"))
+            .and_then(|mut f| f.read_to_end(&mut rom))
+            .expect("dmg-acid2.gb is missing from test-roms/dmg-acid2");
+        let reference = image::open(dir.join("reference-dmg.png"))
+            .expect("reference-dmg.png is missing from test-roms/dmg-acid2")
+            .to_luma()
+            .into_raw();
+
+        for &renderer in &[Renderer::Scanline, Renderer::Fifo] {
+            let cart = Cartridge::load(&rom, false).unwrap();
+            let mut gb = GameBoy::new(HardwareModel::DMG, cart, false);
+            gb.set_renderer(renderer);
+
+            // The test screen is drawn within the first few frames and then left alone
+            for _ in 0..0x0A {
+                gb.run().unwrap();
+            }
+
+            let frame = gb.request_frame();
+            let wrong: Vec<(usize, usize)> = (0..reference.len())
+                .filter(|&i| shade(frame.pixels[i].r) != shade(reference[i]))
+                .map(|i| (i % SCREEN_WIDTH, i / SCREEN_WIDTH))
+                .collect();
+            assert!(wrong.is_empty(),
+                    "{} pixels differ from the reference with the {:?} renderer, first at {:?}",
+                    wrong.len(),
+                    renderer,
+                    wrong[0]);
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use std::collections::VecDeque;
+
+// Each step of the background fetcher takes two dots, except pushing, which waits for the
+// background FIFO to empty
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FetcherStep {
+    Tile,
+    DataLow,
+    DataHigh,
+    Push,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct SpritePixel {
+    pub color: u8,
+    pub palette: u8,
+    pub behind_background: bool,
+}
+
+// The state of the pixel FIFO renderer while it draws a line
+pub struct PixelFifo {
+    pub background: VecDeque<u8>,
+    pub sprites: VecDeque<SpritePixel>,
+
+    pub step: FetcherStep,
+    pub step_dots: u8,
+    // Column of the next tile to fetch, counted from SCX or the window's left edge
+    pub fetch_x: u8,
+    pub tile: u8,
+    pub tile_row: u8,
+    pub data_low: u8,
+    pub data_high: u8,
+
+    // The next pixel on the LCD to be drawn
+    pub x: u8,
+    // Pixels thrown away at the start of the line for SCX fine scrolling
+    pub discard: u8,
+    pub window: bool,
+    // The very first tile fetch of each line is thrown away
+    pub startup: u8,
+
+    // OAM indexes of the sprites on this line that haven't been fetched yet
+    pub line_sprites: Vec<usize>,
+    pub pending_sprite: Option<usize>,
+    pub sprite_stall: u8,
+}
+
+impl PixelFifo {
+    pub fn new() -> PixelFifo {
+        PixelFifo {
+            background: VecDeque::with_capacity(0x10),
+            sprites: VecDeque::with_capacity(0x08),
+            step: FetcherStep::Tile,
+            step_dots: 0x00,
+            fetch_x: 0x00,
+            tile: 0x00,
+            tile_row: 0x00,
+            data_low: 0x00,
+            data_high: 0x00,
+            x: 0x00,
+            discard: 0x00,
+            window: false,
+            startup: 0x00,
+            line_sprites: Vec::new(),
+            pending_sprite: None,
+            sprite_stall: 0x00,
+        }
+    }
+
+    pub fn start_line(&mut self, scroll_x: u8, line_sprites: Vec<usize>) {
+        *self = PixelFifo::new();
+        self.discard = scroll_x % 0x08;
+        self.startup = 0x06;
+        self.line_sprites = line_sprites;
+    }
+
+    // The window restarts the fetcher from its own tilemap, dropping whatever background
+    // pixels were queued along with any SCX fine scrolling still to be done
+    pub fn start_window(&mut self, discard: u8) {
+        self.background.clear();
+        self.discard = discard;
+        self.step = FetcherStep::Tile;
+        self.step_dots = 0x00;
+        self.fetch_x = 0x00;
+        self.window = true;
+    }
+
+    // Pixels from sprites fetched earlier win, unless they're transparent
+    pub fn push_sprite(&mut self, pixels: &[SpritePixel]) {
+        for (i, p) in pixels.iter().enumerate() {
+            if i < self.sprites.len() {
+                if self.sprites[i].color == 0x00 {
+                    self.sprites[i] = *p;
+                }
+            } else {
+                self.sprites.push_back(*p);
+            }
+        }
+    }
+
+    pub fn pop(&mut self) -> Option<(u8, Option<SpritePixel>)> {
+        let background = self.background.pop_front()?;
+
+        Some((background, self.sprites.pop_front()))
+    }
+}
@@ -4,9 +4,14 @@ use std;
 
 use gameboy;
 use gameboy::{Interconnect, Interrupt, Irq, Memory};
-use gameboy::gfx::{Color, Frame, GpuMode, GpuStat, SpriteShape, TileRenderOptions, TileRenderType};
+use gameboy::gfx::{Color, FetcherStep, Frame, GpuMode, GpuStat, PixelFifo, Renderer, SpritePixel,
+                   SpriteShape, TileRenderOptions, TileRenderType};
 use gameboy::ui::theme::Theme;
 
+const OAM_DOTS: usize = 0x50;
+const LINE_DOTS: usize = 0x1C8;
+const MAX_LINE_SPRITES: usize = 0x0A;
+
 pub struct Gpu {
     pub enabled: bool,
     pub ram: Memory,
@@ -30,6 +35,9 @@ pub struct Gpu {
     palette1: u8,
 
     cycles: usize,
+    // The HBlank, VBlank, OAM and LY=LYC sources are ORed into one line, and the LCD
+    // interrupt is only requested as it goes high
+    stat_line: bool,
 
     counter: u8,
     tile_data_addr: usize,
@@ -39,13 +47,24 @@ pub struct Gpu {
     sprites_enabled: bool,
     background_enabled: bool,
     window_enabled: bool,
+    // The window keeps its own line counter, which only advances on lines where it was drawn
+    window_line: u8,
+    window_rendered: bool,
+    // Set once LY has matched WY this frame, the window can't show before then
+    window_triggered: bool,
+    // With WX=166 the window carries on across the whole of the next line
+    window_wrap: bool,
 
     pub theme: Theme,
+    pub renderer: Renderer,
+    fifo: PixelFifo,
+    // Background color indexes of the line the scanline renderer is drawing
+    background_line: [u8; gameboy::SCREEN_WIDTH],
 }
 
 impl Gpu {
     pub fn new() -> Gpu {
-        Gpu {
+        let mut gpu = Gpu {
             enabled: true,
             ram: Memory::new(gameboy::gfx::VRAM_SIZE),
             sprite_data: Memory::new(gameboy::gfx::SPRITE_DATA_SIZE),
@@ -61,6 +80,7 @@ impl Gpu {
             palette0: 0x00,
             palette1: 0x00,
             cycles: 0x00,
+            stat_line: false,
             frame: Frame::new(),
             backbuffer: Frame::new(),
             mode: GpuMode::HBlank,
@@ -72,13 +92,26 @@ impl Gpu {
             sprites_enabled: true,
             background_enabled: true,
             window_enabled: true,
+            window_line: 0x00,
+            window_rendered: false,
+            window_triggered: false,
+            window_wrap: false,
 
             theme: Theme::Default,
-        }
+            renderer: Renderer::Fifo,
+            fifo: PixelFifo::new(),
+            background_line: [0x00; gameboy::SCREEN_WIDTH],
+        };
+
+        // The LCD starts out switched on, at the start of line 0
+        gpu.compare_window_y();
+        gpu
     }
 
     pub fn reset(&mut self) {
+        let renderer = self.renderer;
         *self = Gpu::new();
+        self.renderer = renderer;
     }
 
     pub fn step(&mut self, irq: &mut Irq, cycles: usize) -> Result<(), String> {
@@ -86,57 +119,351 @@ impl Gpu {
             return Ok(());
         }
 
+        match self.renderer {
+            Renderer::Scanline => self.step_scanline(irq, cycles),
+            Renderer::Fifo => {
+                for _ in 0..cycles {
+                    self.step_dot(irq);
+                }
+            }
+        }
+
+        // LY already reads 0 for all but the first few dots of line 153
+        if self.mode == GpuMode::VBlank && self.ly == 0x99 && self.cycles >= 0x04 {
+            self.ly = 0x00;
+        }
+
+        self.update_stat_line(irq);
+
+        Ok(())
+    }
+
+    fn step_scanline(&mut self, irq: &mut Irq, cycles: usize) {
         self.cycles += cycles;
 
         match self.mode {
             GpuMode::OAM => {
-                    if self.cycles >= 0x50 {
-                        self.cycles = 0x00;
-                        self.mode = GpuMode::VRAM;
-                    }
-            },
+                if self.cycles >= OAM_DOTS {
+                    self.cycles = 0x00;
+                    self.mode = GpuMode::VRAM;
+                }
+            }
             GpuMode::VRAM => {
                 if self.cycles >= 0xAC {
                     self.cycles = 0x00;
                     self.mode = GpuMode::HBlank;
-                    if self.enabled {
-                        self.render_scanline();
-                    }
+                    self.render_scanline();
                 }
-            },
+            }
             GpuMode::HBlank => {
                 if self.cycles >= 0xCC {
                     self.cycles = 0x00;
-                    self.ly += 0x01;
-
-                    if self.ly == 0x8F {
-                        self.mode = GpuMode::VBlank;
-                        self.frame = self.backbuffer.clone();
-                        irq.request(Interrupt::Vblank);
-                    } else {
-                        self.mode = GpuMode::OAM;
-                    }
+                    self.next_line(irq);
                 }
-            },
+            }
             GpuMode::VBlank => {
-                if self.cycles >= 0x1C8 {
+                if self.cycles >= LINE_DOTS {
                     self.cycles = 0x00;
-                    self.ly += 0x01;
+                    self.next_line(irq);
+                }
+            }
+        }
+    }
+
+    // Here `cycles` counts dots since the start of the line, as mode 3 varies in length
+    fn step_dot(&mut self, irq: &mut Irq) {
+        self.cycles += 0x01;
 
-                    if self.ly > 0x99 {
-                        self.mode = GpuMode::OAM;
-                        self.ly = 0x00;
-                    }
+        match self.mode {
+            GpuMode::OAM => {
+                if self.cycles == OAM_DOTS {
+                    self.mode = GpuMode::VRAM;
+                    let sprites = self.scan_oam();
+                    self.fifo.start_line(self.scroll_x, sprites);
+                }
+            }
+            GpuMode::VRAM => {
+                if self.fifo_dot() {
+                    self.mode = GpuMode::HBlank;
+                }
+            }
+            GpuMode::HBlank | GpuMode::VBlank => {
+                if self.cycles >= LINE_DOTS {
+                    self.cycles = 0x00;
+                    self.next_line(irq);
                 }
             }
         }
+    }
 
-        self.check_coincidence(irq);
+    fn next_line(&mut self, irq: &mut Irq) {
+        if self.window_rendered {
+            self.window_line = self.window_line.wrapping_add(0x01);
+            self.window_wrap = self.window_x == 0xA6;
+        } else {
+            self.window_wrap = false;
+        }
+        self.window_rendered = false;
 
-        Ok(())
+        if self.mode == GpuMode::VBlank {
+            // LY has been 0 since early in line 153, so this is the end of the frame
+            if self.ly == 0x00 {
+                self.mode = GpuMode::OAM;
+                self.start_frame();
+            } else {
+                self.ly += 0x01;
+            }
+        } else {
+            self.ly += 0x01;
+
+            if self.ly == 0x90 {
+                self.mode = GpuMode::VBlank;
+                self.frame = self.backbuffer.clone();
+                irq.request(Interrupt::Vblank);
+            } else {
+                self.mode = GpuMode::OAM;
+            }
+        }
+
+        if self.mode == GpuMode::OAM {
+            self.compare_window_y();
+        }
+    }
+
+    // WY is only compared against LY as each line starts, and as the LCD is switched on
+    fn compare_window_y(&mut self) {
+        if self.ly == self.window_y {
+            self.window_triggered = true;
+        }
+    }
+
+    fn start_frame(&mut self) {
+        self.window_line = 0x00;
+        self.window_rendered = false;
+        self.window_triggered = false;
+        self.window_wrap = false;
+    }
+
+    // WX values above 166 keep the window off the screen
+    fn window_visible(&self) -> bool {
+        self.window_enabled && self.window_triggered && (self.window_wrap || self.window_x <= 0xA6)
+    }
+
+    // WX as the renderers should treat it. A window carried over from WX=166 starts at the
+    // left edge, as it would with WX=7.
+    fn window_start_x(&self) -> u8 {
+        if self.window_wrap { 0x07 } else { self.window_x }
+    }
+
+    // The first 10 sprites in OAM that cover this line
+    fn scan_oam(&self) -> Vec<usize> {
+        let height = self.sprite_height();
+
+        (0..40)
+            .filter(|i| {
+                let y = self.sprite_data[i * 0x04] as i16 - 0x10;
+                y <= self.ly as i16 && y + height > self.ly as i16
+            })
+            .take(MAX_LINE_SPRITES)
+            .collect()
+    }
+
+    fn sprite_height(&self) -> i16 {
+        if let SpriteShape::Rectangle = self.sprite_shape {
+            0x10
+        } else {
+            0x08
+        }
+    }
+
+    // Advances the pixel FIFO by a dot. Returns true once the line has been drawn.
+    fn fifo_dot(&mut self) -> bool {
+        if self.fifo.startup > 0x00 {
+            self.fifo.startup -= 0x01;
+            return false;
+        }
+
+        // Background fetching and pixel output stop while a sprite is fetched
+        if self.fifo.sprite_stall > 0x00 {
+            self.fifo.sprite_stall -= 0x01;
+            if self.fifo.sprite_stall == 0x00 {
+                if let Some(i) = self.fifo.pending_sprite.take() {
+                    self.fetch_sprite(i);
+                }
+            }
+            return false;
+        }
+
+        let window_x = self.window_start_x();
+        if !self.fifo.window && self.window_visible() && self.fifo.x + 0x07 >= window_x {
+            // With WX below 7 the window's first few pixels are off the left edge
+            self.fifo.start_window(0x07u8.saturating_sub(window_x));
+            self.window_rendered = true;
+        }
+
+        if self.sprites_enabled && self.fifo.discard == 0x00 && !self.fifo.background.is_empty() {
+            let x = self.fifo.x as usize;
+            let sprite_data = &self.sprite_data;
+            let next = self.fifo
+                .line_sprites
+                .iter()
+                .position(|i| sprite_data[i * 0x04 + 0x01] as usize <= x + 0x08);
+
+            if let Some(pos) = next {
+                let i = self.fifo.line_sprites.remove(pos);
+                self.fifo.pending_sprite = Some(i);
+                self.fifo.sprite_stall = 0x06;
+                return false;
+            }
+        }
+
+        self.fetcher_dot();
+
+        if let Some((background, sprite)) = self.fifo.pop() {
+            if self.fifo.discard > 0x00 {
+                self.fifo.discard -= 0x01;
+                return false;
+            }
+
+            let c = self.mix_pixel(background, sprite);
+            self.backbuffer.pixels[self.ly as usize * gameboy::SCREEN_WIDTH + self.fifo.x as usize] = c;
+            self.fifo.x += 0x01;
+
+            return self.fifo.x as usize == gameboy::SCREEN_WIDTH;
+        }
+
+        false
+    }
+
+    fn fetcher_dot(&mut self) {
+        if self.fifo.step == FetcherStep::Push {
+            if self.fifo.background.is_empty() {
+                for bit in (0..0x08).rev() {
+                    let low = (self.fifo.data_low >> bit) & 0x01;
+                    let high = (self.fifo.data_high >> bit) & 0x01;
+                    self.fifo.background.push_back((high << 0x01) | low);
+                }
+
+                self.fifo.fetch_x = self.fifo.fetch_x.wrapping_add(0x01);
+                self.fifo.step = FetcherStep::Tile;
+            }
+            return;
+        }
+
+        self.fifo.step_dots += 0x01;
+        if self.fifo.step_dots < 0x02 {
+            return;
+        }
+        self.fifo.step_dots = 0x00;
+
+        // SCX, SCY and LCDC are read as each tile is fetched, so changes take effect mid-line
+        match self.fifo.step {
+            FetcherStep::Tile => {
+                let (map_addr, map_col, y) = if self.fifo.window {
+                    (self.window_tilemap_addr, self.fifo.fetch_x as usize, self.window_line)
+                } else {
+                    let col = ((self.scroll_x / 0x08) as usize + self.fifo.fetch_x as usize) & 0x1F;
+                    (self.background_tilemap_addr, col, self.ly.wrapping_add(self.scroll_y))
+                };
+
+                let map_row = (y / 0x08) as usize;
+                self.fifo.tile = self.ram[map_addr + (map_row * 0x20 + (map_col & 0x1F))];
+                self.fifo.tile_row = y % 0x08;
+                self.fifo.step = FetcherStep::DataLow;
+            }
+            FetcherStep::DataLow => {
+                let addr = self.tile_data_address(self.fifo.tile, self.fifo.tile_row);
+                self.fifo.data_low = self.ram[addr];
+                self.fifo.step = FetcherStep::DataHigh;
+            }
+            FetcherStep::DataHigh => {
+                let addr = self.tile_data_address(self.fifo.tile, self.fifo.tile_row);
+                self.fifo.data_high = self.ram[addr + 0x01];
+                self.fifo.step = FetcherStep::Push;
+            }
+            FetcherStep::Push => (),
+        }
+    }
+
+    fn fetch_sprite(&mut self, i: usize) {
+        let x = self.sprite_data[i * 0x04 + 0x01] as i16 - 0x08;
+        let pixels = self.sprite_pixels(i);
+
+        // Sprites hanging off the left edge lose the pixels that are off screen
+        let skip = if x < self.fifo.x as i16 {
+            (self.fifo.x as i16 - x) as usize
+        } else {
+            0x00
+        };
+        if skip < pixels.len() {
+            self.fifo.push_sprite(&pixels[skip..]);
+        }
+    }
+
+    // The 8 pixels of sprite `i` on the current line, left to right
+    fn sprite_pixels(&self, i: usize) -> Vec<SpritePixel> {
+        let base = i * 0x04;
+        let y = self.sprite_data[base] as i16 - 0x10;
+        let attributes = self.sprite_data[base + 0x03];
+        let flip_y = attributes & 0x40 == 0x40;
+        let flip_x = attributes & 0x20 == 0x20;
+
+        // Tall sprites ignore bit 0 of the tile number
+        let height = self.sprite_height();
+        let mut tile = self.sprite_data[base + 0x02] as usize;
+        if height == 0x10 {
+            tile &= 0xFE;
+        }
+
+        // The FIFO renderer picks its sprites at the start of the line, so LCDC can have
+        // switched to 8x8 sprites since. Keep the row inside the current height.
+        let mut line = (self.ly as i16 - y) & (height - 0x01);
+        if flip_y {
+            line = height - 0x01 - line;
+        }
+
+        let addr = tile * 0x10 + (line as usize) * 0x02;
+        let low = self.ram[addr];
+        let high = self.ram[addr + 0x01];
+
+        let mut pixels = Vec::with_capacity(0x08);
+        for px in 0..0x08 {
+            let shift = if flip_x { px } else { 0x07 - px };
+            pixels.push(SpritePixel {
+                color: (((high >> shift) & 0x01) << 0x01) | ((low >> shift) & 0x01),
+                palette: (attributes & 0x10) >> 0x04,
+                behind_background: attributes & 0x80 == 0x80,
+            });
+        }
+
+        pixels
+    }
+
+    fn mix_pixel(&self, background: u8, sprite: Option<SpritePixel>) -> Color {
+        let background = if self.background_enabled { background } else { 0x00 };
+
+        if let Some(sprite) = sprite {
+            let hidden = sprite.behind_background && background != 0x00;
+            if self.sprites_enabled && sprite.color != 0x00 && !hidden {
+                return self.get_sprite_color_for_byte(sprite.color, sprite.palette);
+            }
+        }
+
+        self.get_background_color_for_byte(background)
+    }
+
+    fn tile_data_address(&self, tile: u8, row: u8) -> usize {
+        let tile = if self.tile_data_addr == 0x00 {
+            tile as usize
+        } else {
+            (tile as i8 as i16 + 0x80) as usize
+        };
+
+        self.tile_data_addr + tile * 0x10 + ((row as usize) << 0x01)
     }
 
     fn render_scanline(&mut self) {
+        self.background_line = [0x00; gameboy::SCREEN_WIDTH];
         self.render_background();
         self.render_window();
         self.render_sprites();
@@ -155,8 +482,8 @@ impl Gpu {
     }
 
     fn render_window(&mut self) {
-        requires!(self.window_enabled);
-        requires!(self.ly as usize >= self.window_y as usize);
+        requires!(self.window_visible());
+        self.window_rendered = true;
 
         let options = TileRenderOptions::new(
             TileRenderType::Window,
@@ -169,19 +496,19 @@ impl Gpu {
 
     fn render_tile(&mut self, options: &TileRenderOptions) {
         let window = variant_equals!(TileRenderType::Window, options.render_type);
+        // The window is drawn from its own line counter rather than LY
         let y = if window {
-            options.line as usize
+            self.window_line as usize
         } else {
             options.line.wrapping_add(self.scroll_y) as usize
         };
 
-        // If rendering a window tile, we need to make sure we offset tile line properly
-        let window_offset = if window { self.window_y as usize } else { 0x00 };
-        let map_row = ((y - window_offset) / 0x08) as usize;
+        let map_row = (y / 0x08) as usize;
+        let window_x = self.window_start_x() as usize;
         let start = if window {
             // Gameboy manual: "With WX = 7, the window is displayed from the left edge of the LCD screen."
-            // If we don't do this, the window begins rendering slightly to the right
-            self.window_x.wrapping_sub(0x07) as usize
+            // Below 7 the window still starts at the left edge, with its first columns cut off
+            window_x.saturating_sub(0x07)
         } else {
             0
         };
@@ -190,7 +517,7 @@ impl Gpu {
             // If we're at the window, lets negate the window X position from where we
             // need to be in the tile map
             let x = if window {
-                i as u8 - self.window_x
+                (i + 0x07 - window_x) as u8
             } else {
                 // Otherwise, scroll the background
                 (i as u8).wrapping_add(self.scroll_x)
@@ -208,6 +535,7 @@ impl Gpu {
 
             let x_shift = (x % 8).wrapping_sub(0x07).wrapping_mul(0xFF);
             let color_value = Self::build_palette_index(&self.ram[tile_data_start..], x_shift);
+            self.background_line[i] = color_value;
             let c = self.get_background_color_for_byte(color_value as u8);
             self.backbuffer.pixels[self.ly as usize * gameboy::SCREEN_WIDTH + i as usize] = c;
         }
@@ -216,59 +544,38 @@ impl Gpu {
     fn render_sprites(&mut self) {
         requires!(self.sprites_enabled);
 
-        for i in 0..40 {
-            let sprite_table_entry_base = i * 0x04;
-            let s_y = self.sprite_data[sprite_table_entry_base] as i16 - 0x10;
-            let s_x = self.sprite_data[sprite_table_entry_base + 0x01] as i16 - 0x08;
-            let sprite_height = if let SpriteShape::Rectangle = self.sprite_shape {
-                0x10
-            } else {
-                0x08
-            };
+        // On DMG the sprite with the lowest X wins where sprites overlap, then the one
+        // earliest in OAM
+        let mut sprites = self.scan_oam();
+        {
+            let sprite_data = &self.sprite_data;
+            sprites.sort_by_key(|i| sprite_data[i * 0x04 + 0x01]);
+        }
+
+        let mut line: [Option<SpritePixel>; gameboy::SCREEN_WIDTH] = [None; gameboy::SCREEN_WIDTH];
+        for i in sprites {
+            let x = self.sprite_data[i * 0x04 + 0x01] as i16 - 0x08;
+
+            for (px, pixel) in self.sprite_pixels(i).into_iter().enumerate() {
+                let lx = x + px as i16;
+                if lx < 0x00 || lx >= gameboy::SCREEN_WIDTH as i16 || pixel.color == 0x00 {
+                    continue;
+                }
+                if line[lx as usize].is_none() {
+                    line[lx as usize] = Some(pixel);
+                }
+            }
+        }
 
-            if s_y <= self.ly as i16 && (s_y + sprite_height as i16) > self.ly as i16 {
-                let tile_number = self.sprite_data[sprite_table_entry_base + 0x02] as i16;
-                let attributes = self.sprite_data[sprite_table_entry_base + 0x03];
-                let above_background = attributes & 0x80 == 0x00;
-                let flip_y = attributes & 0x40 == 0x40;
-                let flip_x = attributes & 0x20 == 0x20;
-
-                let sprite_line = (self.ly as i16 - s_y) << 0x01;
-                let tile_data_start = (tile_number * 0x10) + sprite_line;
-                for x in 0..8 {
-                    if s_x + x < 0 || s_x + x >= gameboy::SCREEN_WIDTH as i16 {
-                        continue;
-                    }
-                    let shift = if flip_x { x } else { 0x07 - x };
-                    let color_value = Self::build_palette_index(
-                        &self.ram[tile_data_start as usize..],
-                        shift as u8,
-                    );
-                    if color_value == 0x00 {
-                        continue;
-                    }
-                    let c = self.get_sprite_color_for_byte(
-                        color_value as u8,
-                        ((attributes & 0x10) >> 0x04) as u8,
-                    );
-                    let idx =
-                        self.ly as usize * gameboy::SCREEN_WIDTH + (s_x as usize + x as usize);
-                    if idx > self.backbuffer.pixels.len() - 1 {
-                        panic!(format!(
-                            "Just tried to place a pixel outside framebuffer size. \
-                             Index was {} and backbuffer length is {}. line: {:b}, \
-                             gameboy::SCREEN_WIDTH: {:b}, s_x: {:b}, x: {:b}",
-                            idx,
-                            self.backbuffer.pixels.len(),
-                            self.ly,
-                            gameboy::SCREEN_WIDTH,
-                            s_x,
-                            x
-                        ));
-                    } else {
-                        self.backbuffer.pixels[idx] = c;
-                    }
+        for (x, pixel) in line.iter().enumerate() {
+            if let Some(pixel) = *pixel {
+                // The winning sprite can still be hidden behind background colors 1-3
+                if pixel.behind_background && self.background_line[x] != 0x00 {
+                    continue;
                 }
+
+                let c = self.get_sprite_color_for_byte(pixel.color, pixel.palette);
+                self.backbuffer.pixels[self.ly as usize * gameboy::SCREEN_WIDTH + x] = c;
             }
         }
     }
@@ -355,6 +662,10 @@ impl Gpu {
                     self.ly = 0x00;
                     self.mode = GpuMode::HBlank;
                     self.frame = Frame::new();
+                    self.stat_line = false;
+                    self.start_frame();
+                } else if !was_enabled && self.enabled {
+                    self.compare_window_y();
                 }
             }
             0x41 => self.stat = GpuStat::from(val),
@@ -378,11 +689,30 @@ impl Gpu {
         LittleEndian::write_u16(&mut self.ram[addr..], val)
     }
 
-    fn check_coincidence(&mut self, irq: &mut Irq) {
-        // If LY == LYC then set the coincidence flag
-        if self.lyc == self.ly && self.stat.coincidence_interrupt_enabled {
+    fn update_stat_line(&mut self, irq: &mut Irq) {
+        let line = (self.stat.HBlank_interrupt_enabled && self.mode == GpuMode::HBlank) ||
+                   (self.stat.VBlank_interrupt_enabled && self.mode == GpuMode::VBlank) ||
+                   (self.stat.OAM_interrupt_enabled && self.mode == GpuMode::OAM) ||
+                   (self.stat.coincidence_interrupt_enabled && self.ly == self.lyc);
+
+        // While one source holds the line high, the others can't raise another interrupt
+        if line && !self.stat_line {
             irq.request(Interrupt::Lcd);
         }
+        self.stat_line = line;
+    }
+
+    // On DMG, writing to STAT enables every source for a cycle before the new value lands,
+    // so a write during HBlank, VBlank or LY=LYC raises an interrupt if the line was low
+    pub fn stat_write_glitch(&mut self, irq: &mut Irq) {
+        requires!(self.enabled);
+
+        let active = self.mode == GpuMode::HBlank || self.mode == GpuMode::VBlank ||
+                     self.ly == self.lyc;
+        if active && !self.stat_line {
+            irq.request(Interrupt::Lcd);
+            self.stat_line = true;
+        }
     }
 
     fn build_palette_index(data: &[u8], x_shift: u8) -> u8 {
@@ -392,3 +722,374 @@ impl Gpu {
         pixel_palette_entry
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use gameboy::{Interrupt, Irq, M_CYCLE, SCREEN_WIDTH};
+    use gameboy::gfx::{Gpu, GpuMode, Renderer};
+
+    // Steps a dot at a time until LY is `ly` and the GPU is in `mode`
+    fn run_until(gpu: &mut Gpu, irq: &mut Irq, ly: u8, mode: GpuMode) {
+        while gpu.ly != ly || gpu.mode != mode {
+            gpu.step(irq, 0x01).unwrap();
+        }
+    }
+
+    fn mode_3_dots(gpu: &mut Gpu, irq: &mut Irq, ly: u8) -> usize {
+        run_until(gpu, irq, ly, GpuMode::VRAM);
+
+        let mut dots = 0x00;
+        while gpu.mode == GpuMode::VRAM {
+            gpu.step(irq, 0x01).unwrap();
+            dots += 0x01;
+        }
+        dots
+    }
+
+    #[test]
+    fn mode_3_is_longer_by_the_fine_scroll() {
+        let mut irq = Irq::new();
+        let mut gpu = Gpu::new();
+        gpu.write_u8(0x40, 0x91);
+
+        assert_eq!(mode_3_dots(&mut gpu, &mut irq, 0x05), 172);
+
+        gpu.write_u8(0x43, 0x03);
+        assert_eq!(mode_3_dots(&mut gpu, &mut irq, 0x06), 175);
+    }
+
+    #[test]
+    fn sprite_height_can_change_during_mode_3() {
+        let mut irq = Irq::new();
+        let mut gpu = Gpu::new();
+        gpu.write_u8(0x40, 0x97);
+
+        // A Y-flipped 8x16 sprite showing its row 10 on line 20
+        gpu.sprite_data.write_u8(0x00, 20 + 16 - 10);
+        gpu.sprite_data.write_u8(0x01, 100);
+        gpu.sprite_data.write_u8(0x02, 0x02);
+        gpu.sprite_data.write_u8(0x03, 0x40);
+
+        run_until(&mut gpu, &mut irq, 20, GpuMode::VRAM);
+        gpu.write_u8(0x40, 0x93);
+        run_until(&mut gpu, &mut irq, 20, GpuMode::HBlank);
+    }
+
+    // Tiles 1-3 are solid colors 1, 3 and 2. Tile 4 has a color 3 top row over color 1, and
+    // tile 5 is solid color 2.
+    fn sprite_test_gpu(renderer: Renderer, lcdc: u8) -> Gpu {
+        let mut gpu = Gpu::new();
+        gpu.renderer = renderer;
+
+        let tiles: [[(u8, u8); 0x08]; 0x05] = [[(0xFF, 0x00); 0x08],
+                                              [(0xFF, 0xFF); 0x08],
+                                              [(0x00, 0xFF); 0x08],
+                                              [(0xFF, 0xFF), (0xFF, 0x00), (0xFF, 0x00),
+                                               (0xFF, 0x00), (0xFF, 0x00), (0xFF, 0x00),
+                                               (0xFF, 0x00), (0xFF, 0x00)],
+                                              [(0x00, 0xFF); 0x08]];
+        for (t, tile) in tiles.iter().enumerate() {
+            for (row, &(low, high)) in tile.iter().enumerate() {
+                let addr = (t + 0x01) * 0x10 + row * 0x02;
+                gpu.ram[addr] = low;
+                gpu.ram[addr + 0x01] = high;
+            }
+        }
+
+        // Background color 1 on lines 16-23, color 0 everywhere else
+        for col in 0..0x20 {
+            gpu.ram[0x1800 + 0x40 + col] = 0x01;
+        }
+
+        gpu.write_u8(0x40, lcdc);
+        gpu.write_u8(0x47, 0xE4);
+        gpu.write_u8(0x48, 0xE4);
+        gpu.write_u8(0x49, 0xE4);
+        gpu
+    }
+
+    fn add_sprite(gpu: &mut Gpu, i: usize, x: u8, y: u8, tile: u8, attributes: u8) {
+        gpu.sprite_data[i * 0x04] = y + 0x10;
+        gpu.sprite_data[i * 0x04 + 0x01] = x + 0x08;
+        gpu.sprite_data[i * 0x04 + 0x02] = tile;
+        gpu.sprite_data[i * 0x04 + 0x03] = attributes;
+    }
+
+    // The color index drawn at (x, y), with identity palettes and the default theme
+    fn shade(gpu: &mut Gpu, x: usize, y: usize) -> u8 {
+        let mut irq = Irq::new();
+        while gpu.ly != 0x90 {
+            gpu.step(&mut irq, M_CYCLE).unwrap();
+        }
+
+        match gpu.frame.pixels[y * SCREEN_WIDTH + x].r {
+            0xFF => 0x00,
+            0xC0 => 0x01,
+            0x60 => 0x02,
+            _ => 0x03,
+        }
+    }
+
+    // Sprite priority and selection as dmg-acid2 checks them, built by hand since the ROM
+    // itself isn't part of the repository
+    #[test]
+    fn sprite_priority() {
+        for &renderer in &[Renderer::Scanline, Renderer::Fifo] {
+            let mut gpu = sprite_test_gpu(renderer, 0x93);
+
+            // Only the first ten sprites on a line are drawn
+            for i in 0..0x0B {
+                add_sprite(&mut gpu, i, i as u8 * 0x0C, 40, 0x02, 0x00);
+            }
+
+            // Lower X wins where sprites overlap, then lower OAM index
+            add_sprite(&mut gpu, 0x0B, 20, 60, 0x04, 0x00);
+            add_sprite(&mut gpu, 0x0C, 16, 60, 0x02, 0x00);
+            add_sprite(&mut gpu, 0x0D, 60, 70, 0x05, 0x00);
+            add_sprite(&mut gpu, 0x0E, 60, 70, 0x02, 0x00);
+
+            // Behind background colors 1-3, but not color 0. A winning sprite that's hidden
+            // doesn't let the sprite under it through.
+            add_sprite(&mut gpu, 0x0F, 40, 16, 0x02, 0x80);
+            add_sprite(&mut gpu, 0x10, 40, 100, 0x02, 0x80);
+            add_sprite(&mut gpu, 0x11, 80, 16, 0x02, 0x80);
+            add_sprite(&mut gpu, 0x12, 84, 16, 0x03, 0x00);
+
+            // Vertical flip
+            add_sprite(&mut gpu, 0x13, 100, 120, 0x04, 0x40);
+
+            let expected = [(108, 40, 0x03),
+                            (120, 40, 0x00),
+                            (21, 60, 0x03),
+                            (61, 70, 0x02),
+                            (41, 16, 0x01),
+                            (41, 100, 0x03),
+                            (85, 16, 0x01),
+                            (89, 16, 0x02),
+                            (101, 120, 0x01),
+                            (101, 127, 0x03)];
+            for &(x, y, color) in &expected {
+                assert_eq!(shade(&mut gpu, x, y), color, "({}, {}) {:?}", x, y, renderer);
+            }
+        }
+    }
+
+    #[test]
+    fn tall_sprites_ignore_bit_0_of_the_tile() {
+        for &renderer in &[Renderer::Scanline, Renderer::Fifo] {
+            let mut gpu = sprite_test_gpu(renderer, 0x97);
+            add_sprite(&mut gpu, 0x00, 20, 30, 0x05, 0x00);
+            add_sprite(&mut gpu, 0x01, 40, 60, 0x04, 0x40);
+
+            let expected = [(21, 30, 0x03), (21, 38, 0x02), (41, 60, 0x02), (41, 75, 0x03)];
+            for &(x, y, color) in &expected {
+                assert_eq!(shade(&mut gpu, x, y), color, "({}, {}) {:?}", x, y, renderer);
+            }
+        }
+    }
+
+    // Steps to the start of line `ly`, on to the next frame if it has already gone by
+    fn run_to_line(gpu: &mut Gpu, ly: u8) {
+        let mut irq = Irq::new();
+        run_until(gpu, &mut irq, ly, GpuMode::OAM);
+    }
+
+    // The window map at 0x9C00 filled in by `tile(row, col)`, WY=0 and WX=7. The background
+    // is as in `sprite_test_gpu`, color 1 on lines 16-23 and 0 elsewhere.
+    fn window_test_gpu<F>(renderer: Renderer, tile: F) -> Gpu
+        where F: Fn(usize, usize) -> u8
+    {
+        let mut gpu = sprite_test_gpu(renderer, 0xF1);
+        for row in 0..0x20 {
+            for col in 0..0x20 {
+                gpu.ram[0x1C00 + row * 0x20 + col] = tile(row, col);
+            }
+        }
+        gpu.write_u8(0x4B, 0x07);
+        gpu
+    }
+
+    #[test]
+    fn window_line_counter_only_counts_drawn_lines() {
+        for &renderer in &[Renderer::Scanline, Renderer::Fifo] {
+            // Window rows alternate between colors 1, 3 and 2
+            let mut gpu = window_test_gpu(renderer, |row, _| (row % 0x03) as u8 + 0x01);
+
+            run_to_line(&mut gpu, 0);
+            run_to_line(&mut gpu, 24);
+            gpu.write_u8(0x40, 0xD1);
+            run_to_line(&mut gpu, 40);
+            gpu.write_u8(0x40, 0xF1);
+
+            // Line 45 draws window line 29 and line 50 window line 34, rather than picking up
+            // at rows 5 and 6 as LY would
+            let expected = [(10, 10, 0x03), (10, 30, 0x00), (10, 45, 0x01), (10, 50, 0x03)];
+            for &(x, y, color) in &expected {
+                assert_eq!(shade(&mut gpu, x, y), color, "({}, {}) {:?}", x, y, renderer);
+            }
+        }
+    }
+
+    #[test]
+    fn wy_is_latched_once_per_frame() {
+        for &renderer in &[Renderer::Scanline, Renderer::Fifo] {
+            let mut gpu = window_test_gpu(renderer, |row, _| (row % 0x03) as u8 + 0x01);
+            gpu.write_u8(0x4A, 30);
+
+            // Moving WY once the window has started doesn't stop it
+            run_to_line(&mut gpu, 0);
+            run_to_line(&mut gpu, 40);
+            gpu.write_u8(0x4A, 100);
+            assert_eq!(shade(&mut gpu, 10, 20), 0x01, "{:?}", renderer);
+            assert_eq!(shade(&mut gpu, 10, 60), 0x01, "{:?}", renderer);
+
+            // The next frame starts it from the new WY
+            run_to_line(&mut gpu, 0);
+            assert_eq!(shade(&mut gpu, 10, 60), 0x00, "{:?}", renderer);
+            assert_eq!(shade(&mut gpu, 10, 110), 0x03, "{:?}", renderer);
+        }
+    }
+
+    #[test]
+    fn wy_is_compared_when_the_lcd_turns_on() {
+        for &renderer in &[Renderer::Scanline, Renderer::Fifo] {
+            // Straight from power on, as in the post-boot state
+            let mut gpu = window_test_gpu(renderer, |_, _| 0x02);
+            assert_eq!(shade(&mut gpu, 10, 10), 0x03, "{:?}", renderer);
+
+            let mut gpu = window_test_gpu(renderer, |_, _| 0x02);
+            gpu.write_u8(0x40, 0x71);
+            gpu.write_u8(0x40, 0xF1);
+            assert_eq!(shade(&mut gpu, 10, 10), 0x03, "{:?}", renderer);
+        }
+    }
+
+    #[test]
+    fn wx_below_7_clips_the_window() {
+        for &renderer in &[Renderer::Scanline, Renderer::Fifo] {
+            // Window columns alternate between colors 1 and 3
+            let mut gpu = window_test_gpu(renderer, |_, col| (col % 0x02) as u8 + 0x01);
+
+            // Screen X is WX - 7, so the first 7 - WX window columns are off the left edge
+            for wx in 0..0x08 {
+                gpu.write_u8(0x4B, wx);
+                run_to_line(&mut gpu, 0);
+                let x = wx as usize;
+                assert_eq!(shade(&mut gpu, x, 10), 0x01, "WX={} {:?}", wx, renderer);
+                assert_eq!(shade(&mut gpu, x + 0x01, 10), 0x03, "WX={} {:?}", wx, renderer);
+            }
+        }
+    }
+
+    #[test]
+    fn wx_166_carries_over_to_the_next_line() {
+        for &renderer in &[Renderer::Scanline, Renderer::Fifo] {
+            let mut gpu = window_test_gpu(renderer, |_, _| 0x03);
+            gpu.write_u8(0x4A, 20);
+            gpu.write_u8(0x4B, 0xA6);
+            run_to_line(&mut gpu, 0);
+
+            let expected = [(159, 19, 0x01), (100, 20, 0x01), (159, 20, 0x02), (0, 21, 0x02),
+                            (100, 21, 0x02)];
+            for &(x, y, color) in &expected {
+                assert_eq!(shade(&mut gpu, x, y), color, "({}, {}) {:?}", x, y, renderer);
+            }
+        }
+    }
+
+    // Counts the LCD interrupts requested while stepping a dot at a time to `ly` and `mode`
+    fn lcd_interrupts(gpu: &mut Gpu, ly: u8, mode: GpuMode) -> usize {
+        let mut irq = Irq::new();
+        let mut count = 0x00;
+
+        while gpu.ly != ly || gpu.mode != mode {
+            gpu.step(&mut irq, 0x01).unwrap();
+            if irq.requested(&Interrupt::Lcd) {
+                irq.unrequest(Interrupt::Lcd);
+                count += 0x01;
+            }
+        }
+        count
+    }
+
+    // A GPU in mode 3 of line 1, with STAT set to `stat` and nothing yet holding the line high
+    fn stat_test_gpu(renderer: Renderer, stat: u8, lyc: u8) -> Gpu {
+        let mut gpu = Gpu::new();
+        gpu.renderer = renderer;
+        gpu.write_u8(0x40, 0x91);
+        lcd_interrupts(&mut gpu, 0x01, GpuMode::VRAM);
+
+        gpu.write_u8(0x45, lyc);
+        gpu.write_u8(0x41, stat);
+        gpu
+    }
+
+    #[test]
+    fn stat_interrupts_fire_on_the_rising_edge() {
+        for &renderer in &[Renderer::Scanline, Renderer::Fifo] {
+            // Once per HBlank, however many dots it lasts
+            let mut gpu = stat_test_gpu(renderer, 0x08, 0xFF);
+            assert_eq!(lcd_interrupts(&mut gpu, 0x04, GpuMode::VRAM), 3, "{:?}", renderer);
+
+            // Once when LY matches LYC, not once per dot of the line
+            let mut gpu = stat_test_gpu(renderer, 0x40, 0x03);
+            assert_eq!(lcd_interrupts(&mut gpu, 0x05, GpuMode::VRAM), 1, "{:?}", renderer);
+        }
+    }
+
+    #[test]
+    fn a_high_stat_line_blocks_other_sources() {
+        for &renderer in &[Renderer::Scanline, Renderer::Fifo] {
+            // HBlank runs straight into the next line's OAM scan, so OAM never raises its own
+            let mut gpu = stat_test_gpu(renderer, 0x28, 0xFF);
+            assert_eq!(lcd_interrupts(&mut gpu, 0x04, GpuMode::VRAM), 3, "{:?}", renderer);
+
+            // LY=LYC holds the line high from HBlank of line 1 to the end of line 2, so only
+            // HBlank of lines 1 and 3 get through
+            let mut gpu = stat_test_gpu(renderer, 0x48, 0x02);
+            assert_eq!(lcd_interrupts(&mut gpu, 0x04, GpuMode::OAM), 2, "{:?}", renderer);
+        }
+    }
+
+    #[test]
+    fn lyc_0_matches_on_line_153() {
+        for &renderer in &[Renderer::Scanline, Renderer::Fifo] {
+            let mut gpu = stat_test_gpu(renderer, 0x40, 0x00);
+            assert_eq!(lcd_interrupts(&mut gpu, 0x98, GpuMode::VBlank), 0, "{:?}", renderer);
+
+            // LY reads 0 a few dots into line 153, while still in VBlank
+            assert_eq!(lcd_interrupts(&mut gpu, 0x00, GpuMode::VBlank), 1, "{:?}", renderer);
+            assert_eq!(gpu.read_u8(0x41) & 0x07, 0x05, "{:?}", renderer);
+
+            // The match carries on into line 0, which doesn't raise another
+            assert_eq!(lcd_interrupts(&mut gpu, 0x00, GpuMode::VRAM), 0, "{:?}", renderer);
+        }
+    }
+
+    #[test]
+    fn dmg_stat_writes_raise_an_interrupt() {
+        let mut irq = Irq::new();
+        let mut gpu = stat_test_gpu(Renderer::Fifo, 0x00, 0xFF);
+
+        // Not during mode 3 with LY and LYC different
+        gpu.stat_write_glitch(&mut irq);
+        assert!(!irq.requested(&Interrupt::Lcd));
+
+        // During HBlank, even with every source disabled
+        lcd_interrupts(&mut gpu, 0x01, GpuMode::HBlank);
+        gpu.stat_write_glitch(&mut irq);
+        assert!(irq.requested(&Interrupt::Lcd));
+
+        // Not while the line is already high
+        let mut irq = Irq::new();
+        let mut gpu = stat_test_gpu(Renderer::Fifo, 0x08, 0xFF);
+        lcd_interrupts(&mut gpu, 0x01, GpuMode::HBlank);
+        gpu.stat_write_glitch(&mut irq);
+        assert!(!irq.requested(&Interrupt::Lcd));
+
+        // Nor with the LCD off
+        gpu.write_u8(0x40, 0x11);
+        gpu.stat_write_glitch(&mut irq);
+        assert!(!irq.requested(&Interrupt::Lcd));
+    }
+}
@@ -1,8 +1,10 @@
 
 mod color;
+mod fifo;
 mod frame;
 mod gpu;
 mod mode;
+mod renderer;
 mod sprite;
 mod stat;
 mod tile_render_options;
@@ -11,9 +13,11 @@ pub use self::color::Color;
 pub use self::gpu::Gpu;
 pub use self::frame::Frame;
 pub use self::mode::GpuMode;
+pub use self::renderer::Renderer;
 pub use self::sprite::SpriteShape;
 pub use self::stat::GpuStat;
 
+use self::fifo::{FetcherStep, PixelFifo, SpritePixel};
 use self::tile_render_options::{TileRenderOptions, TileRenderType};
 
 pub const VRAM_SIZE: usize = 0x4000;
@@ -11,8 +11,8 @@ impl GpuMode {
         match *self {
             GpuMode::HBlank => 0x00,
             GpuMode::VBlank => 0x01,
-            GpuMode::VRAM => 0x02,
-            GpuMode::OAM => 0x03,
+            GpuMode::OAM => 0x02,
+            GpuMode::VRAM => 0x03,
         }
     }
 }
@@ -0,0 +1,7 @@
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Renderer {
+    // Draws each line dot by dot through the pixel FIFO, so mid-line register writes show up
+    Fifo,
+    // Draws a whole line at once as it leaves mode 3. Faster, but mode 3 is a fixed length.
+    Scanline,
+}
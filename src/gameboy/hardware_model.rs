@@ -0,0 +1,284 @@
+use std::str::FromStr;
+
+// The console being emulated. Each one leaves the system in a slightly different state once
+// its boot ROM hands over to the cartridge at 0x0100, and games use that to tell them apart.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum HardwareModel {
+    DMG0,
+    DMG,
+    MGB,
+    SGB,
+    SGB2,
+    CGB,
+    AGB,
+}
+
+// CPU registers as the boot ROM leaves them
+pub struct PostBootRegisters {
+    pub af: u16,
+    pub bc: u16,
+    pub de: u16,
+    pub hl: u16,
+    pub sp: u16,
+}
+
+// The ® tile the DMG and SGB boot ROMs draw next to the logo
+const REGISTERED_TILE: [u8; 0x08] = [0x3C, 0x42, 0xB9, 0xA5, 0xB9, 0xA5, 0x42, 0x3C];
+
+impl HardwareModel {
+    pub fn is_cgb(&self) -> bool {
+        match *self {
+            HardwareModel::CGB | HardwareModel::AGB => true,
+            _ => false,
+        }
+    }
+
+    // `header_checksum` is the byte at 0x14D. The DMG and MGB boot ROMs leave H and C set
+    // unless it is zero.
+    pub fn post_boot_registers(&self, header_checksum: u8) -> PostBootRegisters {
+        use self::HardwareModel::*;
+
+        let dmg_flags = if header_checksum == 0x00 { 0x80 } else { 0xB0 };
+
+        let (af, bc, de, hl) = match *self {
+            DMG0 => (0x0100, 0xFF13, 0x00C1, 0x8403),
+            DMG => (0x0100 | dmg_flags, 0x0013, 0x00D8, 0x014D),
+            MGB => (0xFF00 | dmg_flags, 0x0013, 0x00D8, 0x014D),
+            SGB => (0x0100, 0x0014, 0x0000, 0xC060),
+            SGB2 => (0xFF00, 0x0014, 0x0000, 0xC060),
+            CGB => (0x1180, 0x0000, 0xFF56, 0x000D),
+            AGB => (0x1100, 0x0100, 0xFF56, 0x000D),
+        };
+
+        PostBootRegisters {
+            af: af,
+            bc: bc,
+            de: de,
+            hl: hl,
+            sp: 0xFFFE,
+        }
+    }
+
+    // IO registers as the boot ROM leaves them. Registers that the boot ROM leaves in an
+    // unpredictable state are given the value they most commonly have.
+    pub fn post_boot_io(&self) -> Vec<(u16, u8)> {
+        use self::HardwareModel::*;
+
+        let stat = match *self {
+            DMG0 => 0x81,
+            _ => 0x85,
+        };
+
+        vec![
+            (0xFF00, 0xCF),
+            (0xFF01, 0x00),
+            (0xFF02, 0x7E),
+            (0xFF05, 0x00),
+            (0xFF06, 0x00),
+            (0xFF07, 0xF8),
+            (0xFF0F, 0xE1),
+            (0xFF10, 0x80),
+            (0xFF11, 0xBF),
+            (0xFF12, 0xF3),
+            (0xFF13, 0xFF),
+            (0xFF14, 0xBF),
+            (0xFF16, 0x3F),
+            (0xFF17, 0x00),
+            (0xFF18, 0xFF),
+            (0xFF19, 0xBF),
+            (0xFF1A, 0x7F),
+            (0xFF1B, 0xFF),
+            (0xFF1C, 0x9F),
+            (0xFF1D, 0xFF),
+            (0xFF1E, 0xBF),
+            (0xFF20, 0xFF),
+            (0xFF21, 0x00),
+            (0xFF22, 0x00),
+            (0xFF23, 0xBF),
+            (0xFF24, 0x77),
+            (0xFF25, 0xF3),
+            (0xFF26, 0xF1),
+            (0xFF40, 0x91),
+            (0xFF41, stat),
+            (0xFF42, 0x00),
+            (0xFF43, 0x00),
+            (0xFF45, 0x00),
+            (0xFF47, 0xFC),
+            (0xFF48, 0xFF),
+            (0xFF49, 0xFF),
+            (0xFF4A, 0x00),
+            (0xFF4B, 0x00),
+            (0xFFFF, 0x00),
+        ]
+    }
+
+    // DIV isn't in `post_boot_io` as writing to it resets it
+    pub fn post_boot_div(&self) -> u8 {
+        use self::HardwareModel::*;
+
+        match *self {
+            DMG0 => 0x18,
+            DMG | MGB => 0xAB,
+            SGB | SGB2 => 0xD8,
+            CGB | AGB => 0x1E,
+        }
+    }
+
+    // The Nintendo logo from the cartridge header, as the boot ROM decoded it into VRAM.
+    // Returns (offset into VRAM, byte) pairs.
+    pub fn post_boot_vram(&self, logo: &[u8]) -> Vec<(u16, u8)> {
+        let mut vram = Vec::new();
+
+        // Every logo nibble is doubled in width, and each row is drawn twice. Only the low
+        // bitplane is written.
+        let mut addr = 0x10;
+        for b in logo {
+            for nibble in &[b >> 0x04, b & 0x0F] {
+                let mut row = 0x00;
+                for bit in 0..0x04 {
+                    if nibble & (0x01 << bit) != 0x00 {
+                        row |= 0x03 << (bit * 0x02);
+                    }
+                }
+
+                vram.push((addr, row));
+                vram.push((addr + 0x02, row));
+                addr += 0x04;
+            }
+        }
+
+        // The CGB boot ROM clears the tilemap as it fades the logo out
+        if self.is_cgb() {
+            return vram;
+        }
+
+        for (i, b) in REGISTERED_TILE.iter().enumerate() {
+            vram.push((0x190 + i as u16 * 0x02, *b));
+        }
+
+        // Two rows of 12 tiles, with the ® to the right of the top row
+        for i in 0..0x0C {
+            vram.push((0x1904 + i, i as u8 + 0x01));
+            vram.push((0x1924 + i, i as u8 + 0x0D));
+        }
+        vram.push((0x1910, 0x19));
+
+        vram
+    }
+
+    // The boot ROM we can run for this model, if any. Other models skip straight to their
+    // post-boot state.
+    //
+    // 0x00E0-0x00FF of boot_rom.gb is a hand-written tail, not Nintendo's code. It sets up
+    // the post-boot registers and sums the header like the real ROM, but doesn't lock up
+    // when the checksum is wrong.
+    pub fn boot_rom(&self) -> Option<&'static [u8]> {
+        match *self {
+            HardwareModel::DMG => Some(include_bytes!("boot_rom.gb")),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for HardwareModel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<HardwareModel, String> {
+        use self::HardwareModel::*;
+
+        match s.to_lowercase().as_ref() {
+            "dmg0" => Ok(DMG0),
+            "dmg" => Ok(DMG),
+            "mgb" => Ok(MGB),
+            "sgb" => Ok(SGB),
+            "sgb2" => Ok(SGB2),
+            "cgb" => Ok(CGB),
+            "agb" => Ok(AGB),
+            _ => Err(format!("unknown hardware model: {}", s)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gameboy::GameBoy;
+    use super::HardwareModel;
+    use super::HardwareModel::*;
+
+    const LOGO: [u8; 0x30] = [
+        0xCE, 0xED, 0x66, 0x66, 0xCC, 0x0D, 0x00, 0x0B, 0x03, 0x73, 0x00, 0x83, 0x00, 0x0C, 0x00,
+        0x0D, 0x00, 0x08, 0x11, 0x1F, 0x88, 0x89, 0x00, 0x0E, 0xDC, 0xCC, 0x6E, 0xE6, 0xDD, 0xDD,
+        0xD9, 0x99, 0xBB, 0xBB, 0x67, 0x63, 0x6E, 0x0E, 0xEC, 0xCC, 0xDD, 0xDC, 0x99, 0x9F, 0xBB,
+        0xB9, 0x33, 0x3E,
+    ];
+
+    // A cartridge with the real logo and the given header checksum, booted without a boot ROM
+    fn boot(model: HardwareModel, header_checksum: u8) -> GameBoy {
+        let mut rom = GameBoy::test_rom(&[]);
+        rom[0x104..0x134].copy_from_slice(&LOGO);
+        rom[0x14D] = header_checksum;
+
+        GameBoy::with_rom(model, &rom)
+    }
+
+    fn registers(gb: &GameBoy) -> [u16; 5] {
+        let r = &gb.cpu.registers;
+        [r.get_af(), r.get_bc(), r.get_de(), r.get_hl(), r.sp as u16]
+    }
+
+    #[test]
+    fn registers_match_each_model() {
+        let expected = [
+            (DMG0, [0x0100, 0xFF13, 0x00C1, 0x8403, 0xFFFE]),
+            (DMG, [0x01B0, 0x0013, 0x00D8, 0x014D, 0xFFFE]),
+            (MGB, [0xFFB0, 0x0013, 0x00D8, 0x014D, 0xFFFE]),
+            (SGB, [0x0100, 0x0014, 0x0000, 0xC060, 0xFFFE]),
+            (SGB2, [0xFF00, 0x0014, 0x0000, 0xC060, 0xFFFE]),
+            (CGB, [0x1180, 0x0000, 0xFF56, 0x000D, 0xFFFE]),
+            (AGB, [0x1100, 0x0100, 0xFF56, 0x000D, 0xFFFE]),
+        ];
+
+        for &(model, registers_after_boot) in &expected {
+            assert_eq!(registers_after_boot, registers(&boot(model, 0x01)), "{:?}", model);
+        }
+    }
+
+    // A=0x11 is how games detect a CGB, and B bit 0 tells a GBA apart from it
+    #[test]
+    fn cgb_detection() {
+        for &model in &[DMG0, DMG, MGB, SGB, SGB2, CGB, AGB] {
+            let r = &boot(model, 0x01).cpu.registers;
+            assert_eq!(model.is_cgb(), r.a == 0x11, "{:?}", model);
+            assert_eq!(model == AGB, model.is_cgb() && r.b & 0x01 == 0x01, "{:?}", model);
+        }
+    }
+
+    #[test]
+    fn dmg_flags_depend_on_the_header_checksum() {
+        for &model in &[DMG, MGB] {
+            assert_eq!(0xB0, boot(model, 0x01).cpu.registers.get_af() as u8, "{:?}", model);
+            assert_eq!(0x80, boot(model, 0x00).cpu.registers.get_af() as u8, "{:?}", model);
+        }
+    }
+
+    #[test]
+    fn logo_is_left_in_vram() {
+        for &model in &[DMG0, DMG, MGB, SGB, SGB2, CGB, AGB] {
+            let gb = boot(model, 0x01);
+            let vram = &gb.interconnect.gpu.ram;
+
+            // 0xCE: the first rows of the top-left tile are 0xC and 0xE, doubled in width
+            assert_eq!([0xF0, 0x00, 0xF0, 0x00, 0xFC], vram[0x10..0x15], "{:?}", model);
+
+            if model.is_cgb() {
+                assert_eq!(0x00, vram[0x1904], "{:?}", model);
+                assert_eq!(0x00, vram[0x1910], "{:?}", model);
+            } else {
+                assert_eq!([0x3C, 0x00, 0x42], vram[0x190..0x193], "{:?}", model);
+                assert_eq!(0x01, vram[0x1904], "{:?}", model);
+                assert_eq!(0x0D, vram[0x1924], "{:?}", model);
+                assert_eq!(0x19, vram[0x1910], "{:?}", model);
+            }
+        }
+    }
+}
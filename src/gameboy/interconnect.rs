@@ -1,10 +1,16 @@
+use std::io;
 use std::ops::Range;
 
-use gameboy::{Irq, Memory, Timer};
-use gameboy::cartridge::{Cartridge, CartridgeDetails};
+use gameboy::{Irq, Memory, Timer, M_CYCLE};
+use gameboy::cartridge::Cartridge;
+use gameboy::cartridge_details::CartridgeDetails;
+use gameboy::cheat::Cheats;
+use gameboy::dma::Dma;
+use gameboy::hardware_model::HardwareModel;
 use gameboy::gfx::{Frame, Gpu};
 use gameboy::irq::Interrupt;
 use gameboy::joypad::{Joypad, JoypadButton};
+use gameboy::mbc::{CameraSource, IrTransceiver};
 use gameboy::memory_map::{self, Address};
 use gameboy::serial::Serial;
 
@@ -12,11 +18,10 @@ const MAIN_MEM_SIZE: usize = 0x2000;
 const ZRAM_SIZE: usize = 0x80;
 const MMAP_SIZE: usize = 0x80;
 
-const BOOT_ROM: &'static [u8] = include_bytes!("boot_rom.gb");
-
 pub struct Interconnect {
+    pub model: HardwareModel,
     pub booting: bool,
-    pub boot_rom: Memory,
+    pub boot_rom: Option<Memory>,
     pub gpu: Gpu,
     pub ram: Memory,
     pub zram: Memory,
@@ -28,13 +33,26 @@ pub struct Interconnect {
     pub interrupt: u8,
     pub joypad: Joypad,
     pub serial: Serial,
+    pub cheats: Cheats,
+    pub dma: Dma,
+
+    // CGB-only KEY1 state. STOP performs the speed switch once it has been armed.
+    pub double_speed: bool,
+    pub speed_switch_armed: bool,
+
+    // Cycles ticked since the CPU last collected them
+    cycles: usize,
+    // Peripherals are ticked in the middle of instructions, so any error they raise is
+    // held here until the CPU finishes the instruction and can report it
+    fault: Option<String>,
 }
 
 impl Interconnect {
-    pub fn new() -> Interconnect {
+    pub fn new(model: HardwareModel) -> Interconnect {
         Interconnect {
+            model: model,
             booting: true,
-            boot_rom: Self::init_boot_rom(true),
+            boot_rom: Self::init_boot_rom(model, true),
             gpu: Gpu::new(),
             ram: Memory::new(MAIN_MEM_SIZE),
             zram: Memory::new(ZRAM_SIZE),
@@ -46,13 +64,20 @@ impl Interconnect {
             interrupt: 0x00,
             joypad: Joypad::new(),
             serial: Serial::new(),
+            cheats: Cheats::new(),
+            dma: Dma::new(),
+            double_speed: false,
+            speed_switch_armed: false,
+            cycles: 0x00,
+            fault: None,
         }
     }
 
-    pub fn with_cart(cart: Cartridge, boot_rom_enabled: bool) -> Interconnect {
+    pub fn with_cart(cart: Cartridge, model: HardwareModel, boot_rom_enabled: bool) -> Interconnect {
         Interconnect {
+            model: model,
             booting: true,
-            boot_rom: Self::init_boot_rom(boot_rom_enabled),
+            boot_rom: Self::init_boot_rom(model, boot_rom_enabled),
             gpu: Gpu::new(),
             ram: Memory::new(MAIN_MEM_SIZE),
             zram: Memory::new(ZRAM_SIZE),
@@ -64,40 +89,136 @@ impl Interconnect {
             interrupt: 0x00,
             joypad: Joypad::new(),
             serial: Serial::new(),
+            cheats: Cheats::new(),
+            dma: Dma::new(),
+            double_speed: false,
+            speed_switch_armed: false,
+            cycles: 0x00,
+            fault: None,
         }
     }
 
-    fn init_boot_rom(boot_rom_enabled: bool) -> Memory {
-        if boot_rom_enabled {
-            let mut mem = Memory::new(BOOT_ROM.len());
-            mem.write_bytes(0x00, &BOOT_ROM);
-            mem
-        } else {
-            // This is:
-            //
-            // LD A, $01
-            // LD ($FF00+$32), A
-            //
-
-            let mut mem = Memory::new(0x04);
-            mem.write_bytes(0x00, &[0x3E, 0x01, 0xE0, 0x50]);
-            mem
+    fn init_boot_rom(model: HardwareModel, boot_rom_enabled: bool) -> Option<Memory> {
+        if !boot_rom_enabled {
+            return None;
         }
+
+        model.boot_rom().map(|rom| {
+            let mut mem = Memory::new(rom.len());
+            mem.write_bytes(0x00, rom);
+            mem
+        })
     }
 
     pub fn step(&mut self, cycles: usize) -> Result<(), String> {
-        self.gpu.step(&mut self.irq, cycles)?;
+        // In double speed mode the CPU and timer run twice as fast, but the LCD doesn't
+        let gpu_cycles = if self.double_speed { cycles >> 0x01 } else { cycles };
+
+        self.gpu.step(&mut self.irq, gpu_cycles)?;
         self.timer.step(&mut self.irq, cycles)?;
         self.joypad.step(&mut self.irq, cycles)?;
 
         Ok(())
     }
 
+    // Advances everything but the CPU by one M-cycle
+    pub fn tick(&mut self) {
+        if let Some((from, offset)) = self.dma.tick() {
+            let b = self.read_u8(from);
+            self.gpu.sprite_data.write_u8(offset, b);
+        }
+
+        if let Err(e) = self.step(M_CYCLE) {
+            self.fault.get_or_insert(e);
+        }
+        self.cycles += M_CYCLE;
+    }
+
+    // Returns the cycles ticked since the last call, or the first error raised while ticking
+    pub fn take_cycles(&mut self) -> Result<usize, String> {
+        if let Some(e) = self.fault.take() {
+            return Err(e);
+        }
+
+        Ok(::std::mem::replace(&mut self.cycles, 0x00))
+    }
+
+    // CPU memory accesses each take an M-cycle, during which the rest of the system keeps running
+    pub fn read_cycle_u8(&mut self, addr: u16) -> u8 {
+        self.tick();
+
+        match memory_map::map_address(addr) {
+            Address::Oam(_) if self.dma.blocking_oam() => 0xFF,
+            _ => self.read_u8(addr),
+        }
+    }
+
+    pub fn write_cycle_u8(&mut self, addr: u16, byte: u8) {
+        self.tick();
+
+        match memory_map::map_address(addr) {
+            Address::Oam(_) if self.dma.blocking_oam() => (),
+            _ => self.write_u8(addr, byte),
+        }
+    }
+
+    pub fn read_cycle_u16(&mut self, addr: u16) -> u16 {
+        let a = self.read_cycle_u8(addr);
+        let b = self.read_cycle_u8(addr + 0x01);
+
+        ((b as u16) << 0x08) | a as u16
+    }
+
+    pub fn write_cycle_u16(&mut self, addr: u16, val: u16) {
+        self.write_cycle_u8(addr, val as u8);
+        self.write_cycle_u8(addr + 0x01, (val >> 0x08) as u8);
+    }
+
     pub fn reset(&mut self) {
-        self.booting = true;
+        self.booting = self.boot_rom.is_some();
         self.gpu.reset();
         self.timer.reset();
         self.irq.reset();
+        self.dma = Dma::new();
+        self.double_speed = false;
+        self.speed_switch_armed = false;
+    }
+
+    // Puts the IO registers and VRAM into the state the boot ROM would have left them in
+    pub fn load_post_boot_state(&mut self) {
+        // IF goes last, so interrupts requested as a side effect of the other writes (such as
+        // the DMG STAT write glitch) don't survive into the post-boot state
+        let mut io = self.model.post_boot_io();
+        io.sort_by_key(|&(addr, _)| addr == 0xFF0F);
+
+        for (addr, byte) in io {
+            self.write_u8(addr, byte);
+        }
+        self.timer.set_div(self.model.post_boot_div());
+
+        let logo: Vec<u8> = (0x104..0x134).map(|addr| self.read_u8(addr)).collect();
+        for (addr, byte) in self.model.post_boot_vram(&logo) {
+            self.gpu.ram.write_u8(addr, byte);
+        }
+    }
+
+    // Called when the CPU executes STOP. Returns true if this was a CGB speed switch, rather
+    // than a request to enter low power mode.
+    pub fn stop(&mut self) -> bool {
+        // DIV is reset either way
+        self.timer.write_u8(0x04, 0x00);
+
+        if self.model.is_cgb() && self.speed_switch_armed {
+            self.speed_switch_armed = false;
+            self.double_speed = !self.double_speed;
+            return true;
+        }
+
+        false
+    }
+
+    fn boot_rom_len(&self) -> usize {
+        self.boot_rom.as_ref().map(|rom| rom.len()).unwrap_or(0x00)
     }
 
     pub fn cart_details(&self) -> &CartridgeDetails {
@@ -106,6 +227,48 @@ impl Interconnect {
         &cart.details
     }
 
+    // GameShark codes are re-applied every frame, so the game can't overwrite them
+    pub fn apply_cheats(&mut self) {
+        for (addr, value, bank) in self.cheats.ram_writes() {
+            match (memory_map::map_address(addr), bank) {
+                (Address::CartRam(a), Some(bank)) => {
+                    let cart = self.cart.as_mut().expect("Cartridge is empty");
+                    cart.write_ram_bank_u8(bank as usize, a, value);
+                }
+                _ => self.write_u8(addr, value),
+            }
+        }
+    }
+
+    pub fn flush_save_file(&mut self) -> io::Result<()> {
+        match self.cart.as_mut() {
+            Some(cart) => cart.flush_save_file(),
+            None => Ok(()),
+        }
+    }
+
+    pub fn set_tilt(&mut self, x: f32, y: f32) {
+        if let Some(cart) = self.cart.as_mut() {
+            cart.set_tilt(x, y);
+        }
+    }
+
+    pub fn set_ir_transceiver(&mut self, transceiver: Box<IrTransceiver>) {
+        if let Some(cart) = self.cart.as_mut() {
+            cart.set_ir_transceiver(transceiver);
+        }
+    }
+
+    pub fn set_camera_source(&mut self, source: Box<CameraSource>) {
+        if let Some(cart) = self.cart.as_mut() {
+            cart.set_camera_source(source);
+        }
+    }
+
+    pub fn rumble(&self) -> bool {
+        self.cart.as_ref().map(|cart| cart.rumble()).unwrap_or(false)
+    }
+
     pub fn request_frame(&self) -> &Frame {
         &self.gpu.frame
     }
@@ -121,7 +284,7 @@ impl Interconnect {
     pub fn write_u8(&mut self, addr: u16, byte: u8) {
         // Special case - DMA transfer
         if addr == 0xFF46 {
-            self.dma_transfer(byte);
+            self.dma.start(byte);
             return;
         }
         let cart = self.cart.as_mut().expect("Cartridge is empty");
@@ -148,20 +311,31 @@ impl Interconnect {
                         self.irq.request(Interrupt::Serial);
                     }
                     0x04...0x07 => self.timer.write_u8(a, byte),
-                    0x0F => self.irq.request_flag = byte,
+                    0x0F => self.irq.request_flag = byte & 0x1F,
                     0x10...0x26 => (), // println!("err: write to sound driver not supported"),
                     0x30...0x3F => (), // println!("err: write to wave pattern RAM not supported"),
+                    0x41 => {
+                        if !self.model.is_cgb() {
+                            self.gpu.stat_write_glitch(&mut self.irq);
+                        }
+                        self.gpu.write_u8(a, byte);
+                    }
                     0x40...0x45 => self.gpu.write_u8(a, byte),
                     0x47...0x49 => self.gpu.write_u8(a, byte),
                     0x50 => {
+                        // Unmaps the boot ROM, for good
                         if self.booting {
                             self.booting = false;
-                            self.irq.request(Interrupt::LoadGame);
                         } else {
                             self.mmap_io.write_u8(a, byte);
                         }
                     }
                     0x4A...0x4B => self.gpu.write_u8(a, byte),
+                    0x4D => {
+                        if self.model.is_cgb() {
+                            self.speed_switch_armed = byte & 0x01 == 0x01;
+                        }
+                    }
                     0x7F => self.mmap_io.write_u8(a, byte),
                     _ => panic!("write memory mapped I/O in unsupported range: {:04X}", a),
                 }
@@ -181,13 +355,15 @@ impl Interconnect {
             Address::Ram(addr) => self.ram.read_u8(addr),
             Address::RamShadow(addr) => self.ram.read_u8(addr),
             Address::CartRom(addr) => {
-                if self.booting {
-                    self.boot_rom.read_u8(addr)
+                if self.booting && (addr as usize) < self.boot_rom_len() {
+                    self.boot_rom.as_ref().unwrap().read_u8(addr)
                 } else {
-                    cart.read_rom_u8(addr)
+                    self.cheats.patch_rom_read(addr, cart.read_rom_u8(addr))
                 }
             }
-            Address::CartRomOtherBank(addr) => cart.read_rom_u8(addr),
+            Address::CartRomOtherBank(addr) => {
+                self.cheats.patch_rom_read(addr, cart.read_rom_u8(addr))
+            }
             Address::Gfx(value) => self.gpu.ram.read_u8(value),
             Address::CartRam(a) => cart.read_ram_u8(a),
             Address::ZRam(a) => self.zram.read_u8(a),
@@ -199,7 +375,7 @@ impl Interconnect {
                     0x01 => self.serial.data,
                     0x02 => self.serial.transfer_control,
                     0x04...0x07 => self.timer.read_u8(a),
-                    0x0F => self.irq.request_flag,
+                    0x0F => self.irq.request_flag | 0xE0, // The upper 3 bits are unused and read high
                     0x10...0x26 => {
                         // println!("err: read from sound driver not supported");
                         0
@@ -209,8 +385,18 @@ impl Interconnect {
                         0
                     }
                     0x40...0x45 => self.gpu.read_u8(a),
+                    0x46 => self.dma.source,
                     0x47...0x49 => self.gpu.read_u8(a),
                     0x4A...0x4B => self.gpu.read_u8(a),
+                    0x4D => {
+                        if self.model.is_cgb() {
+                            let speed = if self.double_speed { 0x80 } else { 0x00 };
+                            let armed = if self.speed_switch_armed { 0x01 } else { 0x00 };
+                            speed | 0x7E | armed
+                        } else {
+                            0xFF
+                        }
+                    }
                     0x7F => self.mmap_io.read_u8(a),
                     n @ _ => panic!("read memory mapped I/O in unsupported range: {:04X}", n),
                 }
@@ -255,13 +441,13 @@ impl Interconnect {
             Address::Ram(addr) => self.ram.read_u16(addr),
             Address::RamShadow(addr) => self.ram.read_u16(addr),
             Address::CartRom(addr) => {
-                if self.booting {
-                    self.boot_rom.read_u16(addr)
+                if self.booting && (addr as usize) < self.boot_rom_len() {
+                    self.boot_rom.as_ref().unwrap().read_u16(addr)
                 } else {
-                    cart.read_rom_u16(addr)
+                    self.read_rom_u16_with_cheats(addr)
                 }
             }
-            Address::CartRomOtherBank(addr) => cart.read_rom_u16(addr),
+            Address::CartRomOtherBank(addr) => self.read_rom_u16_with_cheats(addr),
             Address::Gfx(value) => self.gpu.ram.read_u16(value),
             Address::CartRam(a) => cart.read_ram_u16(a),
             Address::ZRam(a) => self.zram.read_u16(a),
@@ -270,6 +456,13 @@ impl Interconnect {
         }
     }
 
+    fn read_rom_u16_with_cheats(&self, addr: u16) -> u16 {
+        let a = self.read_u8(addr);
+        let b = self.read_u8(addr + 0x01);
+
+        ((b as u16) << 0x08) | a as u16
+    }
+
     pub fn write_bytes(&mut self, addr: u16, bytes: &[u8]) {
         let cart = self.cart.as_mut().expect("Cartridge is empty");
         match memory_map::map_address(addr) {
@@ -277,12 +470,41 @@ impl Interconnect {
             _ => panic!("write_bytes not mapped for specified memory region"),
         }
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use gameboy::{GameBoy, HardwareModel};
+
+    #[test]
+    fn post_boot_state_has_no_stray_interrupts() {
+        use gameboy::HardwareModel::*;
+
+        for &model in &[DMG0, DMG, MGB, SGB, SGB2, CGB, AGB] {
+            let gb = GameBoy::with_program(model, &[]);
+            assert_eq!(gb.interconnect.read_u8(0xFF0F), 0xE1, "{:?}", model);
+        }
+    }
+
+    // LD HL,DIV; LDH (DIV),A; `nops` NOPs; LD A,(HL). Returns what the load read.
+    fn div_after_nops(nops: usize) -> u8 {
+        let mut program = gb_asm![0x21 0x04 0xFF 0xE0 0x04];
+        program.extend(vec![0x00; nops]);
+        program.extend(gb_asm![0x7E]);
 
-    fn dma_transfer(&mut self, byte: u8) {
-        let addr = (byte as u16) << 0x08; // "The written value specifies the transfer source address divided by 0x100"
-        for x in 0..0xA0 {
-            let val = self.read_u8(addr + x);
-            self.write_u8(0xFE00 + x, val);
+        let mut gb = GameBoy::with_program(HardwareModel::DMG, &program);
+        for _ in 0..nops + 0x03 {
+            gb.cpu.step(&mut gb.interconnect).unwrap();
         }
+        gb.cpu.registers.a
+    }
+
+    // DIV goes up every 64 M-cycles. Resetting it, then 62 NOPs, the opcode fetch and the
+    // read itself make 64, so the read only sees the new DIV if both of the load's M-cycles
+    // have ticked before it happens.
+    #[test]
+    fn reads_happen_after_the_m_cycles_before_them() {
+        assert_eq!(div_after_nops(61), 0x00);
+        assert_eq!(div_after_nops(62), 0x01);
     }
 }
@@ -10,8 +10,8 @@ impl Irq {
     pub fn new() -> Irq {
         Irq {
             request_flag: 0x00,
-            enable_flag: 0x20,
-            enabled: true,
+            enable_flag: 0x00,
+            enabled: false,
         }
     }
 
@@ -23,6 +23,29 @@ impl Irq {
         self.requested(&int) && self.enabled(&int) && self.enabled
     }
 
+    // Interrupts that are both requested and enabled, regardless of IME
+    pub fn pending(&self) -> u8 {
+        self.request_flag & self.enable_flag & 0x1F
+    }
+
+    // The pending interrupt that would be serviced first. Lower bits take priority.
+    pub fn highest_priority(&self) -> Option<Interrupt> {
+        use self::Interrupt::*;
+
+        let pending = self.pending();
+        if pending == 0x00 {
+            return None;
+        }
+
+        match pending.trailing_zeros() {
+            0x00 => Some(Vblank),
+            0x01 => Some(Lcd),
+            0x02 => Some(Timer),
+            0x03 => Some(Serial),
+            _ => Some(Joypad),
+        }
+    }
+
     pub fn request(&mut self, int: Interrupt) {
         use self::Interrupt::*;
 
@@ -32,7 +55,6 @@ impl Irq {
             Timer => self.request_flag |= 0x04,
             Serial => self.request_flag |= 0x08,
             Joypad => self.request_flag |= 0x10,
-            LoadGame => self.request_flag |= 0x20,
             _ => panic!("err: unsupported interrupt"),
         }
     }
@@ -46,7 +68,6 @@ impl Irq {
             Timer => self.request_flag &= !0x04,
             Serial => self.request_flag &= !0x08,
             Joypad => self.request_flag &= !0x10,
-            LoadGame => self.request_flag &= !0x20,
             _ => panic!("err: unsupported interrupt"),
         }
     }
@@ -60,7 +81,6 @@ impl Irq {
             Timer => self.request_flag & 0x04 == 0x04,
             Serial => self.request_flag & 0x08 == 0x08,
             Joypad => self.request_flag & 0x10 == 0x10,
-            LoadGame => self.request_flag & 0x20 == 0x20,
             _ => panic!("err: unsupported interrupt"),
         }
     }
@@ -74,7 +94,6 @@ impl Irq {
             Timer => self.enable_flag |= 0x04,
             Serial => self.enable_flag |= 0x08,
             Joypad => self.enable_flag |= 0x10,
-            LoadGame => self.enable_flag |= 0x20,
             _ => panic!("err: unsupported interrupt"),
         }
     }
@@ -83,12 +102,11 @@ impl Irq {
         use self::Interrupt::*;
 
         match int {
-            Vblank => self.enable_flag -= 0x01,
-            Lcd => self.enable_flag -= 0x02,
-            Timer => self.enable_flag -= 0x04,
-            Serial => self.enable_flag -= 0x08,
-            Joypad => self.enable_flag -= 0x10,
-            LoadGame => self.enable_flag -= 0x20,
+            Vblank => self.enable_flag &= !0x01,
+            Lcd => self.enable_flag &= !0x02,
+            Timer => self.enable_flag &= !0x04,
+            Serial => self.enable_flag &= !0x08,
+            Joypad => self.enable_flag &= !0x10,
             _ => panic!("err: unsupported interrupt"),
         }
     }
@@ -102,7 +120,6 @@ impl Irq {
             Timer => self.enable_flag & 0x04 == 0x04,
             Serial => self.enable_flag & 0x08 == 0x08,
             Joypad => self.enable_flag & 0x10 == 0x10,
-            LoadGame => self.enable_flag & 0x20 == 0x20,
             _ => panic!("err: unsupported interrupt"),
         }
     }
@@ -116,5 +133,19 @@ pub enum Interrupt {
     Serial,
     OAM,
     Joypad,
-    LoadGame, // This is a custom interrupt for the custom boot rom
+}
+
+impl Interrupt {
+    pub fn vector(&self) -> u16 {
+        use self::Interrupt::*;
+
+        match *self {
+            Vblank => 0x40,
+            Lcd => 0x48,
+            Timer => 0x50,
+            Serial => 0x58,
+            Joypad => 0x60,
+            _ => panic!("err: unsupported interrupt"),
+        }
+    }
 }
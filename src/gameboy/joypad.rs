@@ -63,6 +63,19 @@ impl Joypad {
         self.data = d;
     }
 
+    // True while a held button is on a selected line, pulling it low. This is what wakes the
+    // CPU from STOP.
+    pub fn line_low(&self) -> bool {
+        let lines = match self.data & 0x30 {
+            0x00 => (self.state >> 0x04) & self.state,
+            0x10 => self.state >> 0x04,
+            0x20 => self.state,
+            _ => 0x0F,
+        };
+
+        lines & 0x0F != 0x0F
+    }
+
     pub fn from_u8(&mut self, b: u8, irq: &mut Irq) {
         self.data = (self.data & 0xCF) | (b & 0x30);
         self.update(irq);
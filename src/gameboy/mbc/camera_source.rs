@@ -0,0 +1,63 @@
+use std::path::Path;
+
+use image::{self, FilterType};
+
+pub const SENSOR_WIDTH: usize = 128;
+pub const SENSOR_HEIGHT: usize = 128;
+
+// Whatever the camera is pointed at. Each capture returns one 128x128 frame of 8-bit
+// luminance, row by row, with 0x00 as black.
+pub trait CameraSource {
+    fn capture(&mut self) -> Vec<u8>;
+}
+
+// A lens cap, every capture is the same flat grey
+pub struct BlankSource;
+
+impl CameraSource for BlankSource {
+    fn capture(&mut self) -> Vec<u8> {
+        vec![0x80; SENSOR_WIDTH * SENSOR_HEIGHT]
+    }
+}
+
+// Still images loaded with the image crate. With more than one image each capture
+// moves on to the next frame, looping back to the first once they run out.
+pub struct ImageSource {
+    frames: Vec<Vec<u8>>,
+    next: usize,
+}
+
+impl ImageSource {
+    pub fn open<P: AsRef<Path>>(paths: &[P]) -> Result<ImageSource, String> {
+        if paths.len() == 0x00 {
+            return Err("no camera images given".into());
+        }
+
+        let mut frames = Vec::new();
+        for p in paths {
+            let img = image::open(p).map_err(|e| {
+                format!("could not open camera image {}: {}", p.as_ref().display(), e)
+            })?;
+
+            let frame = img.resize_exact(SENSOR_WIDTH as u32,
+                                         SENSOR_HEIGHT as u32,
+                                         FilterType::Triangle)
+                .to_luma()
+                .into_raw();
+            frames.push(frame);
+        }
+
+        Ok(ImageSource {
+            frames: frames,
+            next: 0x00,
+        })
+    }
+}
+
+impl CameraSource for ImageSource {
+    fn capture(&mut self) -> Vec<u8> {
+        let frame = self.frames[self.next].clone();
+        self.next = (self.next + 0x01) % self.frames.len();
+        frame
+    }
+}
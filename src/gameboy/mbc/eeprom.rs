@@ -0,0 +1,204 @@
+// 93LC56 serial EEPROM - 128 16-bit words driven over a 3-wire (Microwire) bus
+
+use byteorder::{ByteOrder, LittleEndian};
+
+pub const EEPROM_WORDS: usize = 0x80;
+pub const EEPROM_SIZE: usize = EEPROM_WORDS * 0x02;
+
+// Start bit + 2 opcode bits + 8 address bits
+const COMMAND_BITS: u8 = 0x0B;
+
+enum EepromState {
+    // Waiting for a start bit
+    Idle,
+    Command,
+    Read { word: u16, remaining: u8 },
+    Write { addr: Option<usize>, bits: u8 },
+    // Waiting for CS to drop once a command has finished
+    Done,
+}
+
+pub struct Eeprom {
+    pub data: [u16; EEPROM_WORDS],
+
+    state: EepromState,
+    shift: u16,
+    bits: u8,
+    read_addr: usize,
+    write_enabled: bool,
+
+    cs: bool,
+    clk: bool,
+    di: bool,
+    dout: bool,
+}
+
+impl Eeprom {
+    pub fn new() -> Eeprom {
+        Eeprom {
+            // A blank EEPROM reads back as all 1s
+            data: [0xFFFF; EEPROM_WORDS],
+            state: EepromState::Idle,
+            shift: 0x00,
+            bits: 0x00,
+            read_addr: 0x00,
+            write_enabled: false,
+            cs: false,
+            clk: false,
+            di: false,
+            dout: true,
+        }
+    }
+
+    // bit 7 = CS, bit 6 = CLK, bit 1 = DI, bit 0 = DO
+    pub fn read_u8(&self) -> u8 {
+        (if self.cs { 0x80 } else { 0x00 }) | (if self.clk { 0x40 } else { 0x00 }) |
+        (if self.di { 0x02 } else { 0x00 }) | (if self.dout { 0x01 } else { 0x00 })
+    }
+
+    pub fn write_u8(&mut self, b: u8) {
+        let cs = b & 0x80 == 0x80;
+        let clk = b & 0x40 == 0x40;
+        self.di = b & 0x02 == 0x02;
+
+        if !cs {
+            // Dropping CS aborts whatever was in progress and reports ready
+            self.state = EepromState::Idle;
+            self.dout = true;
+        } else if clk && !self.clk {
+            let di = self.di;
+            self.clock(di);
+        }
+
+        self.cs = cs;
+        self.clk = clk;
+    }
+
+    fn clock(&mut self, di: bool) {
+        let bit = if di { 0x01 } else { 0x00 };
+
+        match self.state {
+            EepromState::Idle => {
+                if di {
+                    self.state = EepromState::Command;
+                    self.shift = 0x01;
+                    self.bits = 0x01;
+                }
+            }
+            EepromState::Command => {
+                self.shift = (self.shift << 0x01) | bit;
+                self.bits += 0x01;
+                if self.bits == COMMAND_BITS {
+                    self.execute();
+                }
+            }
+            EepromState::Read { word, remaining } => {
+                self.dout = word & 0x8000 == 0x8000;
+                self.state = if remaining > 0x01 {
+                    EepromState::Read {
+                        word: word << 0x01,
+                        remaining: remaining - 0x01,
+                    }
+                } else {
+                    // Sequential reads roll on to the next word
+                    self.read_addr = (self.read_addr + 0x01) % EEPROM_WORDS;
+                    EepromState::Read {
+                        word: self.data[self.read_addr],
+                        remaining: 0x10,
+                    }
+                };
+            }
+            EepromState::Write { addr, bits } => {
+                self.shift = (self.shift << 0x01) | bit;
+                if bits + 0x01 < 0x10 {
+                    self.state = EepromState::Write {
+                        addr: addr,
+                        bits: bits + 0x01,
+                    };
+                    return;
+                }
+
+                if self.write_enabled {
+                    match addr {
+                        Some(a) => self.data[a] = self.shift,
+                        None => self.data = [self.shift; EEPROM_WORDS],
+                    }
+                }
+                self.dout = true;
+                self.state = EepromState::Done;
+            }
+            EepromState::Done => (),
+        }
+    }
+
+    fn execute(&mut self) {
+        let opcode = (self.shift >> 0x08) & 0x03;
+        let addr = (self.shift & 0x7F) as usize;
+
+        self.state = EepromState::Done;
+        self.dout = true;
+
+        match opcode {
+            // READ - a dummy 0 bit, then the word MSB first
+            0x02 => {
+                self.read_addr = addr;
+                self.dout = false;
+                self.state = EepromState::Read {
+                    word: self.data[addr],
+                    remaining: 0x10,
+                };
+            }
+            // WRITE
+            0x01 => {
+                self.shift = 0x00;
+                self.state = EepromState::Write {
+                    addr: Some(addr),
+                    bits: 0x00,
+                };
+            }
+            // ERASE
+            0x03 => {
+                if self.write_enabled {
+                    self.data[addr] = 0xFFFF;
+                }
+            }
+            _ => {
+                match (self.shift >> 0x06) & 0x03 {
+                    0x00 => self.write_enabled = false, // EWDS
+                    0x01 => {
+                        // WRAL
+                        self.shift = 0x00;
+                        self.state = EepromState::Write {
+                            addr: None,
+                            bits: 0x00,
+                        };
+                    }
+                    0x02 => {
+                        // ERAL
+                        if self.write_enabled {
+                            self.data = [0xFFFF; EEPROM_WORDS];
+                        }
+                    }
+                    _ => self.write_enabled = true, // EWEN
+                }
+            }
+        }
+    }
+
+    pub fn save(&self) -> Vec<u8> {
+        let mut data = vec![0x00; EEPROM_SIZE];
+        for (i, word) in self.data.iter().enumerate() {
+            LittleEndian::write_u16(&mut data[i * 0x02..], *word);
+        }
+
+        data
+    }
+
+    pub fn load(&mut self, data: &[u8]) {
+        for (i, chunk) in data.chunks(0x02).take(EEPROM_WORDS).enumerate() {
+            if chunk.len() == 0x02 {
+                self.data[i] = LittleEndian::read_u16(chunk);
+            }
+        }
+    }
+}
@@ -0,0 +1,109 @@
+// HuC1 - Hudson's MBC1 lookalike, with an infrared port that can replace the RAM window
+
+use gameboy::Memory;
+use gameboy::mbc::mbc::{get_ram_size, load_ram_from};
+use gameboy::mbc::MBC;
+use gameboy::mbc::ir::{IrTransceiver, NullTransceiver};
+
+pub struct HuC1 {
+    pub rom: Memory,
+    pub ram: Memory,
+    pub ir: Box<IrTransceiver>,
+
+    ir_mode: bool,
+    rom_bank: usize,
+    ram_bank: usize,
+}
+
+impl HuC1 {
+    pub fn new(rom: &[u8]) -> HuC1 {
+        let mut r = Memory::new(rom.len());
+        r.write_bytes(0x00, rom);
+
+        let ram_size = get_ram_size(rom[0x149]);
+        let ram = Memory::new(ram_size);
+
+        HuC1 {
+            rom: r,
+            ram: ram,
+            ir: Box::new(NullTransceiver),
+            ir_mode: false,
+            rom_bank: 0x01,
+            ram_bank: 0x00,
+        }
+    }
+
+    fn ram_addr(&self, addr: u16) -> Option<usize> {
+        if self.ram.len() == 0x00 {
+            return None;
+        }
+
+        Some(((self.ram_bank * 0x2000) | (addr as usize & 0x1FFF)) % self.ram.len())
+    }
+}
+
+impl MBC for HuC1 {
+    fn read_ram_u8(&self, addr: u16) -> u8 {
+        if self.ir_mode {
+            return 0xC0 | (if self.ir.receiving() { 0x01 } else { 0x00 });
+        }
+
+        self.ram_addr(addr).map(|a| self.ram[a]).unwrap_or(0xFF)
+    }
+
+    fn read_rom_u8(&self, addr: u16) -> u8 {
+        let addr = if addr < 0x4000 {
+            addr as usize
+        } else {
+            self.rom_bank * 0x4000 | (addr as usize & 0x3FFF)
+        };
+
+        self.rom[addr % self.rom.len()]
+    }
+
+    fn write_ram_u8(&mut self, addr: u16, b: u8) {
+        if self.ir_mode {
+            self.ir.set_led(b & 0x01 == 0x01);
+        } else if let Some(a) = self.ram_addr(addr) {
+            self.ram[a] = b;
+        }
+    }
+
+    fn write_rom_u8(&mut self, addr: u16, b: u8) {
+        match addr {
+            // There is no RAM enable, 0x0E switches 0xA000-0xBFFF over to the IR port
+            0x0000...0x1FFF => self.ir_mode = b == 0x0E,
+            0x2000...0x3FFF => {
+                let b = b & 0x3F;
+                self.rom_bank = if b == 0x00 { 0x01 } else { b as usize };
+            }
+            0x4000...0x5FFF => self.ram_bank = b as usize & 0x03,
+            0x6000...0x7FFF => (),
+            _ => panic!("Unsupported address range in HuC1: {:04X}", addr),
+        }
+    }
+
+    fn write_ram_u16(&mut self, addr: u16, b: u16) {
+        self.write_ram_u8(addr, b as u8);
+        self.write_ram_u8(addr + 0x01, (b >> 0x08) as u8);
+    }
+
+    fn write_ram_bank_u8(&mut self, bank: usize, addr: u16, b: u8) {
+        let len = self.ram.len();
+        if len > 0x00 {
+            self.ram[((bank * 0x2000) | (addr as usize & 0x1FFF)) % len] = b;
+        }
+    }
+
+    fn save_ram(&self) -> Vec<u8> {
+        self.ram.to_vec()
+    }
+
+    fn load_ram(&mut self, data: &[u8]) {
+        load_ram_from(&mut self.ram, data);
+    }
+
+    fn set_ir_transceiver(&mut self, transceiver: Box<IrTransceiver>) {
+        self.ir = transceiver;
+    }
+}
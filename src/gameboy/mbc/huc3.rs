@@ -0,0 +1,266 @@
+// HuC3 - HuC1 with a real-time clock and tone generator behind a nibble-wide command port
+
+use byteorder::{ByteOrder, LittleEndian};
+
+use gameboy::Memory;
+use gameboy::mbc::mbc::{get_ram_size, load_ram_from};
+use gameboy::mbc::MBC;
+use gameboy::mbc::ir::{IrTransceiver, NullTransceiver};
+use gameboy::mbc::rtc::{RtcClock, SystemClock};
+
+const MINUTES_PER_DAY: u16 = 0x5A0; // 1,440
+const MAX_DAYS: u16 = 0x1000; // The day counter is 12 bits wide
+
+// Minutes, days and a 64-bit timestamp, appended to the RAM in save files
+const RTC_SAVE_SIZE: usize = 0x10;
+
+// Commands written to 0xA000 in mode 0x0B, with the argument in the low nibble
+const COMMAND_READ: u8 = 0x01;
+const COMMAND_WRITE: u8 = 0x03;
+const COMMAND_ADDRESS_LOW: u8 = 0x04;
+const COMMAND_ADDRESS_HIGH: u8 = 0x05;
+const COMMAND_EXTENDED: u8 = 0x06;
+
+// The chip's nibble memory. 0x00-0x02 hold the minute of the day and 0x03-0x05 the
+// day counter once the time has been latched, the rest is scratch space games use for alarms.
+pub struct HuC3Rtc {
+    pub minutes: u16,
+    pub days: u16,
+    pub memory: [u8; 0x100],
+
+    clock: Box<RtcClock>,
+    last_update: u64,
+    address: u8,
+    response: u8,
+}
+
+impl HuC3Rtc {
+    pub fn new(clock: Box<RtcClock>) -> HuC3Rtc {
+        let now = clock.now();
+
+        HuC3Rtc {
+            minutes: 0x00,
+            days: 0x00,
+            memory: [0x00; 0x100],
+            clock: clock,
+            last_update: now,
+            address: 0x00,
+            response: 0x00,
+        }
+    }
+
+    // Only whole minutes are counted, leftover seconds carry over to the next update
+    pub fn update(&mut self) {
+        let elapsed = self.clock.now().saturating_sub(self.last_update) / 60;
+        self.last_update += elapsed * 60;
+
+        let total = self.minutes as u64 + elapsed;
+        let days = self.days as u64 + total / MINUTES_PER_DAY as u64;
+
+        self.minutes = (total % MINUTES_PER_DAY as u64) as u16;
+        self.days = (days % MAX_DAYS as u64) as u16;
+    }
+
+    pub fn read_response(&self) -> u8 {
+        self.response
+    }
+
+    pub fn write_command(&mut self, b: u8) {
+        let command = (b >> 0x04) & 0x07;
+        let arg = b & 0x0F;
+
+        match command {
+            COMMAND_READ => {
+                self.response = (b & 0xF0) | self.memory[self.address as usize];
+                self.address = self.address.wrapping_add(0x01);
+            }
+            COMMAND_WRITE => {
+                self.memory[self.address as usize] = arg;
+                self.address = self.address.wrapping_add(0x01);
+            }
+            COMMAND_ADDRESS_LOW => self.address = (self.address & 0xF0) | arg,
+            COMMAND_ADDRESS_HIGH => self.address = (self.address & 0x0F) | (arg << 0x04),
+            COMMAND_EXTENDED => self.extended_command(b, arg),
+            _ => (),
+        }
+    }
+
+    fn extended_command(&mut self, b: u8, arg: u8) {
+        match arg {
+            // Copy the current time into 0x00-0x05
+            0x00 => {
+                self.update();
+                let (minutes, days) = (self.minutes, self.days);
+                self.write_nibbles(0x00, minutes);
+                self.write_nibbles(0x03, days);
+            }
+            // Set the time from 0x00-0x05
+            0x01 => {
+                self.update();
+                self.minutes = self.read_nibbles(0x00) % MINUTES_PER_DAY;
+                self.days = self.read_nibbles(0x03);
+            }
+            // Status, the clock is always ready
+            0x02 => self.response = (b & 0xF0) | 0x01,
+            // 0x0E plays a tone through the cartridge speaker, which isn't emulated
+            _ => (),
+        }
+    }
+
+    fn read_nibbles(&self, start: usize) -> u16 {
+        (0..3).fold(0x00, |v, i| v | ((self.memory[start + i] as u16 & 0x0F) << (i * 4)))
+    }
+
+    fn write_nibbles(&mut self, start: usize, v: u16) {
+        for i in 0..3 {
+            self.memory[start + i] = ((v >> (i * 4)) & 0x0F) as u8;
+        }
+    }
+
+    pub fn save(&self) -> Vec<u8> {
+        let mut data = vec![0x00; RTC_SAVE_SIZE];
+
+        LittleEndian::write_u32(&mut data[0x00..], self.minutes as u32);
+        LittleEndian::write_u32(&mut data[0x04..], self.days as u32);
+        LittleEndian::write_u64(&mut data[0x08..], self.last_update);
+
+        data
+    }
+
+    pub fn load(&mut self, data: &[u8]) {
+        if data.len() < RTC_SAVE_SIZE {
+            return;
+        }
+
+        self.minutes = LittleEndian::read_u32(&data[0x00..]) as u16 % MINUTES_PER_DAY;
+        self.days = LittleEndian::read_u32(&data[0x04..]) as u16 % MAX_DAYS;
+
+        // Catch up on however long the emulator was closed for
+        self.last_update = LittleEndian::read_u64(&data[0x08..]);
+        self.update();
+    }
+}
+
+pub struct HuC3 {
+    pub rom: Memory,
+    pub ram: Memory,
+    pub rtc: HuC3Rtc,
+    pub ir: Box<IrTransceiver>,
+
+    // Selects what 0xA000-0xBFFF is connected to:
+    // 0x00 read-only RAM, 0x0A RAM, 0x0B RTC command, 0x0C RTC response, 0x0D semaphore, 0x0E IR
+    mode: u8,
+    rom_bank: usize,
+    ram_bank: usize,
+}
+
+impl HuC3 {
+    pub fn new(rom: &[u8]) -> HuC3 {
+        Self::with_clock(rom, Box::new(SystemClock))
+    }
+
+    pub fn with_clock(rom: &[u8], clock: Box<RtcClock>) -> HuC3 {
+        let mut r = Memory::new(rom.len());
+        r.write_bytes(0x00, rom);
+
+        let ram_size = get_ram_size(rom[0x149]);
+        let ram = Memory::new(ram_size);
+
+        HuC3 {
+            rom: r,
+            ram: ram,
+            rtc: HuC3Rtc::new(clock),
+            ir: Box::new(NullTransceiver),
+            mode: 0x00,
+            rom_bank: 0x01,
+            ram_bank: 0x00,
+        }
+    }
+
+    fn ram_addr(&self, addr: u16) -> Option<usize> {
+        if self.ram.len() == 0x00 {
+            return None;
+        }
+
+        Some(((self.ram_bank * 0x2000) | (addr as usize & 0x1FFF)) % self.ram.len())
+    }
+}
+
+impl MBC for HuC3 {
+    fn read_ram_u8(&self, addr: u16) -> u8 {
+        match self.mode {
+            0x00 | 0x0A => self.ram_addr(addr).map(|a| self.ram[a]).unwrap_or(0xFF),
+            0x0C => self.rtc.read_response(),
+            0x0D => 0xFF, // Commands complete immediately, so the semaphore always reads as ready
+            0x0E => 0xC0 | (if self.ir.receiving() { 0x01 } else { 0x00 }),
+            _ => 0xFF,
+        }
+    }
+
+    fn read_rom_u8(&self, addr: u16) -> u8 {
+        let addr = if addr < 0x4000 {
+            addr as usize
+        } else {
+            self.rom_bank * 0x4000 | (addr as usize & 0x3FFF)
+        };
+
+        self.rom[addr % self.rom.len()]
+    }
+
+    fn write_ram_u8(&mut self, addr: u16, b: u8) {
+        match self.mode {
+            0x0A => {
+                if let Some(a) = self.ram_addr(addr) {
+                    self.ram[a] = b;
+                }
+            }
+            0x0B => self.rtc.write_command(b),
+            0x0E => self.ir.set_led(b & 0x01 == 0x01),
+            _ => (),
+        }
+    }
+
+    fn write_rom_u8(&mut self, addr: u16, b: u8) {
+        match addr {
+            0x0000...0x1FFF => self.mode = b & 0x0F,
+            0x2000...0x3FFF => {
+                let b = b & 0x7F;
+                self.rom_bank = if b == 0x00 { 0x01 } else { b as usize };
+            }
+            0x4000...0x5FFF => self.ram_bank = b as usize & 0x03,
+            0x6000...0x7FFF => (),
+            _ => panic!("Unsupported address range in HuC3: {:04X}", addr),
+        }
+    }
+
+    fn write_ram_u16(&mut self, addr: u16, b: u16) {
+        self.write_ram_u8(addr, b as u8);
+        self.write_ram_u8(addr + 0x01, (b >> 0x08) as u8);
+    }
+
+    fn write_ram_bank_u8(&mut self, bank: usize, addr: u16, b: u8) {
+        let len = self.ram.len();
+        if len > 0x00 {
+            self.ram[((bank * 0x2000) | (addr as usize & 0x1FFF)) % len] = b;
+        }
+    }
+
+    fn save_ram(&self) -> Vec<u8> {
+        let mut data = self.ram.to_vec();
+        data.extend(self.rtc.save());
+        data
+    }
+
+    fn load_ram(&mut self, data: &[u8]) {
+        load_ram_from(&mut self.ram, data);
+
+        let len = self.ram.len();
+        if data.len() > len {
+            self.rtc.load(&data[len..]);
+        }
+    }
+
+    fn set_ir_transceiver(&mut self, transceiver: Box<IrTransceiver>) {
+        self.ir = transceiver;
+    }
+}
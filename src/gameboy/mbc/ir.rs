@@ -0,0 +1,39 @@
+// Infrared port on HuC1/HuC3 cartridges. The cartridge drives an LED and reads back a
+// photodiode, whatever is on the other end of that is up to the transceiver.
+
+pub trait IrTransceiver {
+    fn set_led(&mut self, on: bool);
+    fn receiving(&self) -> bool;
+}
+
+// Nothing on the other end, no light is ever received
+pub struct NullTransceiver;
+
+impl IrTransceiver for NullTransceiver {
+    fn set_led(&mut self, _on: bool) {}
+
+    fn receiving(&self) -> bool {
+        false
+    }
+}
+
+// Sees its own LED, as if the cartridge was pointed at a mirror
+pub struct LoopbackTransceiver {
+    led: bool,
+}
+
+impl LoopbackTransceiver {
+    pub fn new() -> LoopbackTransceiver {
+        LoopbackTransceiver { led: false }
+    }
+}
+
+impl IrTransceiver for LoopbackTransceiver {
+    fn set_led(&mut self, on: bool) {
+        self.led = on;
+    }
+
+    fn receiving(&self) -> bool {
+        self.led
+    }
+}
@@ -0,0 +1,118 @@
+// Mitsubishi M64282FP artificial retina, the image sensor in the Game Boy Camera.
+// The analog side (gain, output voltages) is approximated, the parts games visibly
+// rely on - exposure, edge enhancement and the dithering matrix - are modelled.
+
+use gameboy::mbc::camera_source::{BlankSource, CameraSource, SENSOR_HEIGHT, SENSOR_WIDTH};
+
+pub const IMAGE_WIDTH: usize = 128;
+pub const IMAGE_HEIGHT: usize = 112;
+
+pub const REGISTER_COUNT: usize = 0x36;
+
+const REG_CONTROL: usize = 0x00;
+const REG_EDGE_MODE: usize = 0x01;
+const REG_EXPOSURE_HIGH: usize = 0x02;
+const REG_EXPOSURE_LOW: usize = 0x03;
+const REG_EDGE_RATIO: usize = 0x04;
+const REG_DITHER_START: usize = 0x06;
+
+// Exposure that leaves the source image at its original brightness
+const NEUTRAL_EXPOSURE: i32 = 0x1000;
+
+// Edge enhancement ratio, in quarters, selected by bits 4-6 of register 4
+const EDGE_RATIOS: [i32; 8] = [2, 3, 4, 5, 8, 12, 16, 20];
+
+pub struct M64282FP {
+    pub registers: [u8; REGISTER_COUNT],
+    pub source: Box<CameraSource>,
+}
+
+impl M64282FP {
+    pub fn new() -> M64282FP {
+        M64282FP {
+            registers: [0x00; REGISTER_COUNT],
+            source: Box::new(BlankSource),
+        }
+    }
+
+    // Takes a picture and returns it as 2-bit shades, 0 being white and 3 black
+    pub fn capture(&mut self) -> Vec<u8> {
+        let frame = self.source.capture();
+        let exposure = ((self.registers[REG_EXPOSURE_HIGH] as i32) << 0x08) |
+                       self.registers[REG_EXPOSURE_LOW] as i32;
+
+        // Only the middle 112 lines of the sensor make it into the picture
+        let top = (SENSOR_HEIGHT - IMAGE_HEIGHT) / 2;
+        let pixel = |x: isize, y: isize| -> i32 {
+            let x = x.max(0).min(SENSOR_WIDTH as isize - 1) as usize;
+            let y = (y + top as isize).max(0).min(SENSOR_HEIGHT as isize - 1) as usize;
+            (frame[y * SENSOR_WIDTH + x] as i32 * exposure / NEUTRAL_EXPOSURE).min(0xFF)
+        };
+
+        let mut image = vec![0x00; IMAGE_WIDTH * IMAGE_HEIGHT];
+        for y in 0..IMAGE_HEIGHT {
+            for x in 0..IMAGE_WIDTH {
+                let value = self.enhance_edges(&pixel, x as isize, y as isize);
+                image[y * IMAGE_WIDTH + x] = self.dither(value, x, y);
+            }
+        }
+
+        image
+    }
+
+    // Bits 5-6 of register 1 pick which neighbours feed the edge filter
+    fn enhance_edges<F: Fn(isize, isize) -> i32>(&self, pixel: &F, x: isize, y: isize) -> i32 {
+        let centre = pixel(x, y);
+        let vertical = 2 * centre - pixel(x, y - 1) - pixel(x, y + 1);
+        let horizontal = 2 * centre - pixel(x - 1, y) - pixel(x + 1, y);
+
+        let edge = match (self.registers[REG_EDGE_MODE] >> 0x05) & 0x03 {
+            0x01 => horizontal,
+            0x02 => vertical,
+            0x03 => horizontal + vertical,
+            _ => return self.invert(centre),
+        };
+
+        let ratio = EDGE_RATIOS[((self.registers[REG_EDGE_RATIO] >> 0x04) & 0x07) as usize];
+        self.invert((centre + edge * ratio / 4).max(0x00).min(0xFF))
+    }
+
+    fn invert(&self, value: i32) -> i32 {
+        if self.registers[REG_EDGE_RATIO] & 0x08 == 0x08 {
+            0xFF - value
+        } else {
+            value
+        }
+    }
+
+    // Each cell of the 4x4 matrix holds three thresholds, darkest first
+    fn dither(&self, value: i32, x: usize, y: usize) -> u8 {
+        let cell = REG_DITHER_START + ((y & 0x03) * 4 + (x & 0x03)) * 3;
+        let thresholds = &self.registers[cell..cell + 3];
+
+        if value < thresholds[0] as i32 {
+            0x03
+        } else if value < thresholds[1] as i32 {
+            0x02
+        } else if value < thresholds[2] as i32 {
+            0x01
+        } else {
+            0x00
+        }
+    }
+
+    pub fn read_u8(&self, register: usize) -> u8 {
+        // Only the control register can be read back
+        if register == REG_CONTROL {
+            self.registers[REG_CONTROL]
+        } else {
+            0x00
+        }
+    }
+
+    pub fn write_u8(&mut self, register: usize, b: u8) {
+        if register < REGISTER_COUNT {
+            self.registers[register] = b;
+        }
+    }
+}
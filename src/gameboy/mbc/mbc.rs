@@ -2,6 +2,10 @@ use std::ops::Range;
 
 use byteorder::{ByteOrder, LittleEndian};
 
+use gameboy::Memory;
+use gameboy::mbc::camera_source::CameraSource;
+use gameboy::mbc::ir::IrTransceiver;
+
 pub trait MBC {
     fn read_ram_u8(&self, addr: u16) -> u8;
     fn read_rom_u8(&self, addr: u16) -> u8;
@@ -24,6 +28,33 @@ pub trait MBC {
     fn write_rom_u8(&mut self, addr: u16, b: u8);
 
     fn write_ram_u16(&mut self, addr: u16, b: u16);
+
+    // Writes into a specific RAM bank, regardless of which bank is mapped or whether RAM is enabled
+    fn write_ram_bank_u8(&mut self, _bank: usize, addr: u16, b: u8) {
+        self.write_ram_u8(addr, b)
+    }
+
+    fn save_ram(&self) -> Vec<u8>;
+    fn load_ram(&mut self, data: &[u8]);
+
+    fn rumble(&self) -> bool {
+        false
+    }
+
+    // Tilt in g along each axis, for cartridges with an accelerometer
+    fn set_tilt(&mut self, _x: f32, _y: f32) {}
+
+    // Whatever sits on the other side of the infrared port, for cartridges that have one
+    fn set_ir_transceiver(&mut self, _transceiver: Box<IrTransceiver>) {}
+
+    // What the image sensor sees, for cartridges with a camera
+    fn set_camera_source(&mut self, _source: Box<CameraSource>) {}
+}
+
+// Copies as much of a save file as will fit into cartridge RAM
+pub fn load_ram_from(ram: &mut Memory, data: &[u8]) {
+    let len = ram.len().min(data.len());
+    ram[..len].copy_from_slice(&data[..len]);
 }
 
 pub fn get_ram_size(b: u8) -> usize {
@@ -31,6 +62,8 @@ pub fn get_ram_size(b: u8) -> usize {
         0x01 => 0x800,      // 2KB (2048 bytes)
         0x02 => 0x2000,     // 8KB (8096 bytes)
         0x03 => 0x8000,     // 32KB
+        0x04 => 0x20000,    // 128KB
+        0x05 => 0x10000,    // 64KB
         _ => 0x00,
     }
 }
\ No newline at end of file
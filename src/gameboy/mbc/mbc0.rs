@@ -4,7 +4,7 @@
 use std::ops::Range;
 
 use gameboy::Memory;
-use gameboy::mbc::mbc::get_ram_size;
+use gameboy::mbc::mbc::{get_ram_size, load_ram_from};
 use gameboy::mbc::MBC;
 
 pub struct MBC0 {
@@ -52,4 +52,12 @@ impl MBC for MBC0 {
     fn write_ram_u16(&mut self, addr: u16, b: u16) {
         self.ram.write_u16(addr, b)
     }
+
+    fn save_ram(&self) -> Vec<u8> {
+        self.ram.to_vec()
+    }
+
+    fn load_ram(&mut self, data: &[u8]) {
+        load_ram_from(&mut self.ram, data);
+    }
 }
\ No newline at end of file
@@ -1,9 +1,14 @@
 use std::ops::Range;
 
 use gameboy::Memory;
-use gameboy::mbc::mbc::get_ram_size;
+use gameboy::mbc::mbc::{get_ram_size, load_ram_from};
 use gameboy::mbc::MBC;
 
+const MULTICART_ROM_SIZE: usize = 0x100000; // 1MB
+const MULTICART_GAME_SIZE: usize = 0x40000; // 256KB
+const LOGO_START: usize = 0x104;
+const LOGO_END: usize = 0x134;
+
 pub enum BankMode {
     RomBanking,
     RamBanking,
@@ -14,9 +19,13 @@ pub struct MBC1 {
     pub ram: Memory,
 
     ram_enabled: bool,
-    rom_bank: usize,
-    ram_bank: usize,
+    // BANK1 is the lower 5 bits of the ROM bank, BANK2 is either the upper 2 bits
+    // of the ROM bank or the RAM bank depending on the banking mode
+    bank1: usize,
+    bank2: usize,
     bank_mode: BankMode,
+    // MBC1M multicarts only wire 4 bits of BANK1, so BANK2 selects a 256KB game
+    multicart: bool,
 }
 
 impl MBC1 {
@@ -30,61 +39,86 @@ impl MBC1 {
         MBC1 {
             rom: r,
             ram: ram,
-            ram_enabled: true,
-            rom_bank: 0x00,
-            ram_bank: 0x00,
+            ram_enabled: false,
+            bank1: 0x01,
+            bank2: 0x00,
             bank_mode: BankMode::RomBanking,
+            multicart: Self::is_multicart(rom),
         }
     }
-}
 
-impl MBC for MBC1 {
-    fn read_ram_u8(&self, addr: u16) -> u8 {
-        if !self.ram_enabled {
-            return 0xFF;
+    // There is nothing in the header to identify an MBC1M board, but every game on
+    // one has its own header, so look for the Nintendo logo repeated at each 256KB boundary
+    pub fn is_multicart(rom: &[u8]) -> bool {
+        if rom.len() != MULTICART_ROM_SIZE {
+            return false;
         }
-        self.ram[(self.ram_bank * 0x2000) | ((addr as usize & 0x1FFF))]
+
+        let logo = &rom[LOGO_START..LOGO_END];
+        let games = (0..MULTICART_ROM_SIZE / MULTICART_GAME_SIZE)
+            .filter(|i| {
+                let base = i * MULTICART_GAME_SIZE;
+                &rom[base + LOGO_START..base + LOGO_END] == logo
+            })
+            .count();
+
+        games > 0x01
     }
 
-    fn read_rom_u8(&self, addr: u16) -> u8 {
-        let addr = if addr < 0x4000 {
-            addr as usize
+    fn bank2_shift(&self) -> usize {
+        if self.multicart { 0x04 } else { 0x05 }
+    }
+
+    fn lower_rom_bank(&self) -> usize {
+        // In RAM banking mode BANK2 also applies to 0x0000-0x3FFF
+        match self.bank_mode {
+            BankMode::RomBanking => 0x00,
+            BankMode::RamBanking => self.bank2 << self.bank2_shift(),
+        }
+    }
+
+    fn upper_rom_bank(&self) -> usize {
+        let bank1 = if self.multicart {
+            self.bank1 & 0x0F
         } else {
-            self.rom_bank * 0x4000 | (addr as usize & 0x3FFF)
+            self.bank1
         };
 
-        self.rom[addr]
+        (self.bank2 << self.bank2_shift()) | bank1
     }
 
-    fn read_ram_u16(&self, addr: u16) -> u16 {
-        if !self.ram_enabled {
-            return 0xFFFF;
+    fn ram_addr(&self, addr: u16) -> Option<usize> {
+        if !self.ram_enabled || self.ram.len() == 0x00 {
+            return None;
         }
 
-        let addr = (self.ram_bank * 0x2000) | ((addr as usize & 0x1FFF));
+        let bank = match self.bank_mode {
+            BankMode::RomBanking => 0x00,
+            BankMode::RamBanking => self.bank2,
+        };
 
-        let a = self.ram[addr] as u16;
-        let b = self.ram[addr + 0x01] as u16;
-        let result = (b << 0x08) | a;
-        result
+        Some(((bank * 0x2000) | (addr as usize & 0x1FFF)) % self.ram.len())
     }
+}
 
-    fn read_rom_u16(&self, addr: u16) -> u16 {
-        let addr = if addr < 0x4000 {
-            addr as usize
+impl MBC for MBC1 {
+    fn read_ram_u8(&self, addr: u16) -> u8 {
+        self.ram_addr(addr).map(|a| self.ram[a]).unwrap_or(0xFF)
+    }
+
+    fn read_rom_u8(&self, addr: u16) -> u8 {
+        let bank = if addr < 0x4000 {
+            self.lower_rom_bank()
         } else {
-            self.rom_bank as usize * 0x4000 + (addr as usize - 0x4000)
+            self.upper_rom_bank()
         };
 
-        let a = self.rom[addr] as u16;
-        let b = self.rom[addr + 0x01] as u16;
-        let result = (b << 0x08) | a;
-        result
+        self.rom[(bank * 0x4000 | (addr as usize & 0x3FFF)) % self.rom.len()]
     }
 
     fn write_ram_u8(&mut self, addr: u16, b: u8) {
-        if self.ram_enabled {
-            self.ram.write_u8(addr, b)
+        if let Some(a) = self.ram_addr(addr) {
+            self.ram[a] = b;
         }
     }
 
@@ -92,17 +126,11 @@ impl MBC for MBC1 {
         match addr {
             0x0000...0x1FFF => self.ram_enabled = (b & 0x0F) == 0x0A, // lower 4 bits only
             0x2000...0x3FFF => {
-                let b = if b & 0x1F == 0x00 { 0x01 } else { b & 0x1F };
-                self.rom_bank = (self.rom_bank & 0x60) | b as usize;
-            }
-            0x4000...0x5FFF => {
-                if let BankMode::RomBanking = self.bank_mode {
-                    // BANK2 - we combine the lower 5 bits in BANK1 with the lower 2 bits in BANK2
-                    self.rom_bank = self.rom_bank & 0x1F | (((b as usize) & 0x03) << 0x05);
-                } else {
-                    self.ram_bank = (b as usize & 0x03);
-                }
+                // Only the 5 bits are checked for 0, so banks 0x20/0x40/0x60 can't be mapped here
+                let b = b & 0x1F;
+                self.bank1 = if b == 0x00 { 0x01 } else { b as usize };
             }
+            0x4000...0x5FFF => self.bank2 = b as usize & 0x03,
             0x6000...0x7FFF => {
                 self.bank_mode = if b & 0x01 == 0x01 {
                     BankMode::RamBanking
@@ -118,8 +146,22 @@ impl MBC for MBC1 {
     }
 
     fn write_ram_u16(&mut self, addr: u16, b: u16) {
-        if self.ram_enabled {
-            self.ram.write_u16(addr, b)
+        self.write_ram_u8(addr, b as u8);
+        self.write_ram_u8(addr + 0x01, (b >> 0x08) as u8);
+    }
+
+    fn write_ram_bank_u8(&mut self, bank: usize, addr: u16, b: u8) {
+        let len = self.ram.len();
+        if len > 0x00 {
+            self.ram[((bank * 0x2000) | (addr as usize & 0x1FFF)) % len] = b;
         }
     }
+
+    fn save_ram(&self) -> Vec<u8> {
+        self.ram.to_vec()
+    }
+
+    fn load_ram(&mut self, data: &[u8]) {
+        load_ram_from(&mut self.ram, data);
+    }
 }
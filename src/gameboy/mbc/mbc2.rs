@@ -0,0 +1,90 @@
+// MBC2 - up to 16 ROM banks and 512 half-bytes of built-in RAM
+
+use gameboy::Memory;
+use gameboy::mbc::MBC;
+use gameboy::mbc::mbc::load_ram_from;
+
+const RAM_SIZE: usize = 0x200;
+
+pub struct MBC2 {
+    pub rom: Memory,
+    pub ram: Memory,
+
+    ram_enabled: bool,
+    rom_bank: usize,
+}
+
+impl MBC2 {
+    pub fn new(rom: &[u8]) -> MBC2 {
+        let mut r = Memory::new(rom.len());
+        r.write_bytes(0x00, rom);
+
+        MBC2 {
+            rom: r,
+            // The RAM is inside the controller, so the header RAM size is always 0
+            ram: Memory::new(RAM_SIZE),
+            ram_enabled: false,
+            rom_bank: 0x01,
+        }
+    }
+}
+
+impl MBC for MBC2 {
+    fn read_ram_u8(&self, addr: u16) -> u8 {
+        if !self.ram_enabled {
+            return 0xFF;
+        }
+
+        // Only the lower 9 address bits are wired, so the RAM echoes across 0xA000-0xBFFF.
+        // Only the lower nibble of each byte exists, the upper nibble floats high.
+        self.ram[addr as usize & (RAM_SIZE - 0x01)] | 0xF0
+    }
+
+    fn read_rom_u8(&self, addr: u16) -> u8 {
+        let addr = if addr < 0x4000 {
+            addr as usize
+        } else {
+            self.rom_bank * 0x4000 | (addr as usize & 0x3FFF)
+        };
+
+        self.rom[addr % self.rom.len()]
+    }
+
+    fn write_ram_u8(&mut self, addr: u16, b: u8) {
+        if self.ram_enabled {
+            self.ram[addr as usize & (RAM_SIZE - 0x01)] = b & 0x0F;
+        }
+    }
+
+    fn write_rom_u8(&mut self, addr: u16, b: u8) {
+        match addr {
+            // Bit 8 of the address decides whether this is the RAM enable or the ROM bank
+            0x0000...0x3FFF => {
+                if addr & 0x100 == 0x00 {
+                    self.ram_enabled = (b & 0x0F) == 0x0A;
+                } else {
+                    let b = b & 0x0F;
+                    self.rom_bank = if b == 0x00 { 0x01 } else { b as usize };
+                }
+            }
+            0x4000...0x7FFF => (),
+            _ => panic!(
+                "Unsupported address range in Memory Bank Controller 2: {:04X}",
+                addr
+            ),
+        }
+    }
+
+    fn write_ram_u16(&mut self, addr: u16, b: u16) {
+        self.write_ram_u8(addr, b as u8);
+        self.write_ram_u8(addr + 0x01, (b >> 0x08) as u8);
+    }
+
+    fn save_ram(&self) -> Vec<u8> {
+        self.ram.to_vec()
+    }
+
+    fn load_ram(&mut self, data: &[u8]) {
+        load_ram_from(&mut self.ram, data);
+    }
+}
@@ -0,0 +1,254 @@
+// MBC3 - up to 128 ROM banks, 4 RAM banks and an optional real-time clock
+
+use gameboy::Memory;
+use gameboy::mbc::mbc::{get_ram_size, load_ram_from};
+use gameboy::mbc::MBC;
+use gameboy::mbc::rtc::{Rtc, RtcClock, SystemClock, RTC_SAVE_SIZE};
+
+pub struct MBC3 {
+    pub rom: Memory,
+    pub ram: Memory,
+    pub rtc: Rtc,
+
+    ram_enabled: bool,
+    rom_bank: usize,
+    // 0x00-0x03 selects a RAM bank, 0x08-0x0C selects an RTC register
+    ram_bank: u8,
+}
+
+impl MBC3 {
+    pub fn new(rom: &[u8]) -> MBC3 {
+        Self::with_clock(rom, Box::new(SystemClock))
+    }
+
+    pub fn with_clock(rom: &[u8], clock: Box<RtcClock>) -> MBC3 {
+        let mut r = Memory::new(rom.len());
+        r.write_bytes(0x00, rom);
+
+        let ram_size = get_ram_size(rom[0x149]);
+        let ram = Memory::new(ram_size);
+
+        MBC3 {
+            rom: r,
+            ram: ram,
+            rtc: Rtc::new(clock),
+            ram_enabled: false,
+            rom_bank: 0x01,
+            ram_bank: 0x00,
+        }
+    }
+
+    fn ram_addr(&self, addr: u16) -> Option<usize> {
+        if self.ram.len() == 0x00 {
+            return None;
+        }
+
+        let addr = (self.ram_bank as usize * 0x2000) | (addr as usize & 0x1FFF);
+        Some(addr % self.ram.len())
+    }
+}
+
+impl MBC for MBC3 {
+    fn read_ram_u8(&self, addr: u16) -> u8 {
+        if !self.ram_enabled {
+            return 0xFF;
+        }
+
+        match self.ram_bank {
+            0x00...0x03 => self.ram_addr(addr).map(|a| self.ram[a]).unwrap_or(0xFF),
+            0x08...0x0C => self.rtc.read_u8(self.ram_bank),
+            _ => 0xFF,
+        }
+    }
+
+    fn read_rom_u8(&self, addr: u16) -> u8 {
+        let addr = if addr < 0x4000 {
+            addr as usize
+        } else {
+            self.rom_bank * 0x4000 | (addr as usize & 0x3FFF)
+        };
+
+        self.rom[addr % self.rom.len()]
+    }
+
+    fn write_ram_u8(&mut self, addr: u16, b: u8) {
+        if !self.ram_enabled {
+            return;
+        }
+
+        match self.ram_bank {
+            0x00...0x03 => {
+                if let Some(a) = self.ram_addr(addr) {
+                    self.ram[a] = b;
+                }
+            }
+            0x08...0x0C => self.rtc.write_u8(self.ram_bank, b),
+            _ => (),
+        }
+    }
+
+    fn write_rom_u8(&mut self, addr: u16, b: u8) {
+        match addr {
+            0x0000...0x1FFF => self.ram_enabled = (b & 0x0F) == 0x0A,
+            0x2000...0x3FFF => {
+                let b = b & 0x7F;
+                self.rom_bank = if b == 0x00 { 0x01 } else { b as usize };
+            }
+            0x4000...0x5FFF => self.ram_bank = b,
+            0x6000...0x7FFF => self.rtc.write_latch(b),
+            _ => panic!(
+                "Unsupported address range in Memory Bank Controller 3: {:04X}",
+                addr
+            ),
+        }
+    }
+
+    fn write_ram_u16(&mut self, addr: u16, b: u16) {
+        self.write_ram_u8(addr, b as u8);
+        self.write_ram_u8(addr + 0x01, (b >> 0x08) as u8);
+    }
+
+    fn write_ram_bank_u8(&mut self, bank: usize, addr: u16, b: u8) {
+        let len = self.ram.len();
+        if len > 0x00 {
+            self.ram[((bank * 0x2000) | (addr as usize & 0x1FFF)) % len] = b;
+        }
+    }
+
+    // The clock state is appended after the RAM, the same layout VBA and BGB use
+    fn save_ram(&self) -> Vec<u8> {
+        let mut data = self.ram.to_vec();
+        data.extend(self.rtc.save());
+        data
+    }
+
+    fn load_ram(&mut self, data: &[u8]) {
+        load_ram_from(&mut self.ram, data);
+
+        let ram_size = self.ram.len();
+        if data.len() >= ram_size + RTC_SAVE_SIZE {
+            self.rtc.load(&data[ram_size..]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use gameboy::mbc::MBC;
+    use gameboy::mbc::rtc::RtcClock;
+    use super::MBC3;
+
+    struct FakeClock(Rc<Cell<u64>>);
+
+    impl RtcClock for FakeClock {
+        fn now(&self) -> u64 {
+            self.0.get()
+        }
+    }
+
+    fn mbc3() -> (MBC3, Rc<Cell<u64>>) {
+        let time = Rc::new(Cell::new(1000));
+        let mut mbc = MBC3::with_clock(&[0x00; 0x8000], Box::new(FakeClock(time.clone())));
+        mbc.write_rom_u8(0x0000, 0x0A);
+
+        (mbc, time)
+    }
+
+    fn latch(mbc: &mut MBC3) {
+        mbc.write_rom_u8(0x6000, 0x00);
+        mbc.write_rom_u8(0x6000, 0x01);
+    }
+
+    fn read(mbc: &mut MBC3, register: u8) -> u8 {
+        mbc.write_rom_u8(0x4000, register);
+        mbc.read_ram_u8(0xA000)
+    }
+
+    fn write(mbc: &mut MBC3, register: u8, b: u8) {
+        mbc.write_rom_u8(0x4000, register);
+        mbc.write_ram_u8(0xA000, b);
+    }
+
+    #[test]
+    fn reads_only_change_when_latched() {
+        let (mut mbc, time) = mbc3();
+
+        time.set(1005);
+        assert_eq!(0x00, read(&mut mbc, 0x08));
+
+        // 0x01 on its own, or after anything other than 0x00, doesn't latch
+        mbc.write_rom_u8(0x6000, 0x01);
+        assert_eq!(0x00, read(&mut mbc, 0x08));
+        mbc.write_rom_u8(0x6000, 0x02);
+        mbc.write_rom_u8(0x6000, 0x01);
+        assert_eq!(0x00, read(&mut mbc, 0x08));
+
+        latch(&mut mbc);
+        assert_eq!(0x05, read(&mut mbc, 0x08));
+
+        time.set(1010);
+        assert_eq!(0x05, read(&mut mbc, 0x08));
+        latch(&mut mbc);
+        assert_eq!(0x0A, read(&mut mbc, 0x08));
+    }
+
+    #[test]
+    fn counters_carry_into_each_other() {
+        let (mut mbc, time) = mbc3();
+        write(&mut mbc, 0x08, 59);
+        write(&mut mbc, 0x09, 59);
+        write(&mut mbc, 0x0A, 23);
+        write(&mut mbc, 0x0B, 0xFE);
+
+        time.set(1001);
+        latch(&mut mbc);
+        assert_eq!([0, 0, 0, 0xFF, 0x00],
+                   [read(&mut mbc, 0x08),
+                    read(&mut mbc, 0x09),
+                    read(&mut mbc, 0x0A),
+                    read(&mut mbc, 0x0B),
+                    read(&mut mbc, 0x0C)]);
+
+        // Day 511 rolls over to 0 and sets the carry bit, which stays set until written
+        write(&mut mbc, 0x0C, 0x01);
+        write(&mut mbc, 0x0A, 23);
+        write(&mut mbc, 0x09, 59);
+        write(&mut mbc, 0x08, 59);
+        time.set(1002);
+        latch(&mut mbc);
+        assert_eq!([0, 0, 0, 0x00, 0x80],
+                   [read(&mut mbc, 0x08),
+                    read(&mut mbc, 0x09),
+                    read(&mut mbc, 0x0A),
+                    read(&mut mbc, 0x0B),
+                    read(&mut mbc, 0x0C)]);
+
+        time.set(1003);
+        latch(&mut mbc);
+        assert_eq!(0x80, read(&mut mbc, 0x0C));
+        write(&mut mbc, 0x0C, 0x00);
+        latch(&mut mbc);
+        assert_eq!(0x00, read(&mut mbc, 0x0C));
+    }
+
+    #[test]
+    fn halted_clock_stands_still() {
+        let (mut mbc, time) = mbc3();
+        write(&mut mbc, 0x08, 10);
+        write(&mut mbc, 0x0C, 0x40);
+
+        time.set(1100);
+        latch(&mut mbc);
+        assert_eq!(10, read(&mut mbc, 0x08));
+        assert_eq!(0x40, read(&mut mbc, 0x0C));
+
+        // Time spent halted is never caught up on
+        write(&mut mbc, 0x0C, 0x00);
+        time.set(1103);
+        latch(&mut mbc);
+        assert_eq!(13, read(&mut mbc, 0x08));
+    }
+}
@@ -0,0 +1,119 @@
+// MBC5 - 9-bit ROM bank number, up to 16 RAM banks and an optional rumble motor
+
+use gameboy::Memory;
+use gameboy::mbc::mbc::{get_ram_size, load_ram_from};
+use gameboy::mbc::MBC;
+
+pub struct MBC5 {
+    pub rom: Memory,
+    pub ram: Memory,
+
+    ram_enabled: bool,
+    rom_bank: usize,
+    ram_bank: usize,
+    has_rumble: bool,
+    rumble: bool,
+}
+
+impl MBC5 {
+    pub fn new(rom: &[u8]) -> MBC5 {
+        let mut r = Memory::new(rom.len());
+        r.write_bytes(0x00, rom);
+
+        let ram_size = get_ram_size(rom[0x149]);
+        let ram = Memory::new(ram_size);
+
+        MBC5 {
+            rom: r,
+            ram: ram,
+            ram_enabled: false,
+            rom_bank: 0x01,
+            ram_bank: 0x00,
+            has_rumble: match rom[0x147] {
+                0x1C...0x1E => true,
+                _ => false,
+            },
+            rumble: false,
+        }
+    }
+
+    fn ram_addr(&self, addr: u16) -> Option<usize> {
+        if !self.ram_enabled || self.ram.len() == 0x00 {
+            return None;
+        }
+
+        let addr = (self.ram_bank * 0x2000) | (addr as usize & 0x1FFF);
+        Some(addr % self.ram.len())
+    }
+}
+
+impl MBC for MBC5 {
+    fn read_ram_u8(&self, addr: u16) -> u8 {
+        self.ram_addr(addr).map(|a| self.ram[a]).unwrap_or(0xFF)
+    }
+
+    fn read_rom_u8(&self, addr: u16) -> u8 {
+        // Unlike MBC1/MBC3, bank 0 can be mapped into 0x4000-0x7FFF
+        let addr = if addr < 0x4000 {
+            addr as usize
+        } else {
+            self.rom_bank * 0x4000 | (addr as usize & 0x3FFF)
+        };
+
+        self.rom[addr % self.rom.len()]
+    }
+
+    fn write_ram_u8(&mut self, addr: u16, b: u8) {
+        if let Some(a) = self.ram_addr(addr) {
+            self.ram[a] = b;
+        }
+    }
+
+    fn write_rom_u8(&mut self, addr: u16, b: u8) {
+        match addr {
+            0x0000...0x1FFF => self.ram_enabled = (b & 0x0F) == 0x0A,
+            0x2000...0x2FFF => self.rom_bank = (self.rom_bank & 0x100) | b as usize,
+            0x3000...0x3FFF => {
+                self.rom_bank = (self.rom_bank & 0xFF) | ((b as usize & 0x01) << 0x08)
+            }
+            0x4000...0x5FFF => {
+                if self.has_rumble {
+                    // Rumble carts wire bit 3 to the motor, leaving 3 bits for the RAM bank
+                    self.rumble = b & 0x08 == 0x08;
+                    self.ram_bank = b as usize & 0x07;
+                } else {
+                    self.ram_bank = b as usize & 0x0F;
+                }
+            }
+            0x6000...0x7FFF => (),
+            _ => panic!(
+                "Unsupported address range in Memory Bank Controller 5: {:04X}",
+                addr
+            ),
+        }
+    }
+
+    fn write_ram_u16(&mut self, addr: u16, b: u16) {
+        self.write_ram_u8(addr, b as u8);
+        self.write_ram_u8(addr + 0x01, (b >> 0x08) as u8);
+    }
+
+    fn write_ram_bank_u8(&mut self, bank: usize, addr: u16, b: u8) {
+        let len = self.ram.len();
+        if len > 0x00 {
+            self.ram[((bank * 0x2000) | (addr as usize & 0x1FFF)) % len] = b;
+        }
+    }
+
+    fn save_ram(&self) -> Vec<u8> {
+        self.ram.to_vec()
+    }
+
+    fn load_ram(&mut self, data: &[u8]) {
+        load_ram_from(&mut self.ram, data);
+    }
+
+    fn rumble(&self) -> bool {
+        self.rumble
+    }
+}
@@ -0,0 +1,136 @@
+// MBC7 - a two-axis accelerometer and a 93LC56 EEPROM instead of cartridge RAM
+
+use gameboy::Memory;
+use gameboy::mbc::MBC;
+use gameboy::mbc::eeprom::Eeprom;
+
+// The accelerometer reads 0x81D0 when flat and moves by roughly 0x70 per g
+const ACCELEROMETER_CENTER: f32 = 33232.0; // 0x81D0
+const ACCELEROMETER_G: f32 = 112.0; // 0x70
+const ACCELEROMETER_ERASED: u16 = 0x8000;
+
+pub struct MBC7 {
+    pub rom: Memory,
+    pub eeprom: Eeprom,
+
+    ram_enabled: bool,
+    registers_enabled: bool,
+    rom_bank: usize,
+
+    tilt_x: f32,
+    tilt_y: f32,
+    latched_x: u16,
+    latched_y: u16,
+    latch_erased: bool,
+}
+
+impl MBC7 {
+    pub fn new(rom: &[u8]) -> MBC7 {
+        let mut r = Memory::new(rom.len());
+        r.write_bytes(0x00, rom);
+
+        MBC7 {
+            rom: r,
+            eeprom: Eeprom::new(),
+            ram_enabled: false,
+            registers_enabled: false,
+            rom_bank: 0x01,
+            tilt_x: 0.0,
+            tilt_y: 0.0,
+            latched_x: ACCELEROMETER_ERASED,
+            latched_y: ACCELEROMETER_ERASED,
+            latch_erased: false,
+        }
+    }
+
+    fn accelerometer_value(tilt: f32) -> u16 {
+        let tilt = tilt.max(-1.0).min(1.0);
+        (ACCELEROMETER_CENTER + tilt * ACCELEROMETER_G) as u16
+    }
+}
+
+impl MBC for MBC7 {
+    fn read_ram_u8(&self, addr: u16) -> u8 {
+        // Both enables have to be set, and only 0xA000-0xAFFF is decoded
+        if !self.ram_enabled || !self.registers_enabled || addr >= 0x1000 {
+            return 0xFF;
+        }
+
+        match (addr >> 0x04) & 0x0F {
+            0x02 => self.latched_x as u8,
+            0x03 => (self.latched_x >> 0x08) as u8,
+            0x04 => self.latched_y as u8,
+            0x05 => (self.latched_y >> 0x08) as u8,
+            0x06 => 0x00,
+            0x08 => self.eeprom.read_u8(),
+            _ => 0xFF,
+        }
+    }
+
+    fn read_rom_u8(&self, addr: u16) -> u8 {
+        let addr = if addr < 0x4000 {
+            addr as usize
+        } else {
+            self.rom_bank * 0x4000 | (addr as usize & 0x3FFF)
+        };
+
+        self.rom[addr % self.rom.len()]
+    }
+
+    fn write_ram_u8(&mut self, addr: u16, b: u8) {
+        if !self.ram_enabled || !self.registers_enabled || addr >= 0x1000 {
+            return;
+        }
+
+        match (addr >> 0x04) & 0x0F {
+            0x00 => {
+                if b == 0x55 {
+                    self.latched_x = ACCELEROMETER_ERASED;
+                    self.latched_y = ACCELEROMETER_ERASED;
+                    self.latch_erased = true;
+                }
+            }
+            0x01 => {
+                // A new sample can only be latched after the old one was erased
+                if b == 0xAA && self.latch_erased {
+                    self.latched_x = Self::accelerometer_value(self.tilt_x);
+                    self.latched_y = Self::accelerometer_value(self.tilt_y);
+                    self.latch_erased = false;
+                }
+            }
+            0x08 => self.eeprom.write_u8(b),
+            _ => (),
+        }
+    }
+
+    fn write_rom_u8(&mut self, addr: u16, b: u8) {
+        match addr {
+            0x0000...0x1FFF => self.ram_enabled = (b & 0x0F) == 0x0A,
+            0x2000...0x3FFF => self.rom_bank = b as usize & 0x7F,
+            0x4000...0x5FFF => self.registers_enabled = b == 0x40,
+            0x6000...0x7FFF => (),
+            _ => panic!(
+                "Unsupported address range in Memory Bank Controller 7: {:04X}",
+                addr
+            ),
+        }
+    }
+
+    fn write_ram_u16(&mut self, addr: u16, b: u16) {
+        self.write_ram_u8(addr, b as u8);
+        self.write_ram_u8(addr + 0x01, (b >> 0x08) as u8);
+    }
+
+    fn save_ram(&self) -> Vec<u8> {
+        self.eeprom.save()
+    }
+
+    fn load_ram(&mut self, data: &[u8]) {
+        self.eeprom.load(data);
+    }
+
+    fn set_tilt(&mut self, x: f32, y: f32) {
+        self.tilt_x = x;
+        self.tilt_y = y;
+    }
+}
@@ -1,8 +1,30 @@
 
+mod camera_source;
+mod eeprom;
+mod huc1;
+mod huc3;
+mod ir;
+mod m64282fp;
 mod mbc;
 mod mbc0;
 mod mbc1;
+mod mbc2;
+mod mbc3;
+mod mbc5;
+mod mbc7;
+mod pocket_camera;
+mod rtc;
 
-pub use self::mbc::MBC;
+pub use self::camera_source::{BlankSource, CameraSource, ImageSource};
+pub use self::huc1::HuC1;
+pub use self::huc3::HuC3;
+pub use self::ir::{IrTransceiver, LoopbackTransceiver, NullTransceiver};
+pub use self::mbc::{get_ram_size, MBC};
 pub use self::mbc0::MBC0;
-pub use self::mbc1::MBC1;
\ No newline at end of file
+pub use self::mbc1::MBC1;
+pub use self::mbc2::MBC2;
+pub use self::mbc3::MBC3;
+pub use self::mbc5::MBC5;
+pub use self::mbc7::MBC7;
+pub use self::pocket_camera::PocketCamera;
+pub use self::rtc::{RtcClock, SystemClock};
\ No newline at end of file
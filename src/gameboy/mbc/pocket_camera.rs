@@ -0,0 +1,136 @@
+// Pocket Camera - 64 ROM banks, 128KB of RAM and an M64282FP image sensor whose
+// registers replace the RAM window when bit 4 of the RAM bank is set
+
+use gameboy::Memory;
+use gameboy::mbc::mbc::load_ram_from;
+use gameboy::mbc::MBC;
+use gameboy::mbc::camera_source::CameraSource;
+use gameboy::mbc::m64282fp::{IMAGE_HEIGHT, IMAGE_WIDTH, M64282FP};
+
+const RAM_SIZE: usize = 0x20000; // 128KB
+// Captured pictures land in the first RAM bank as 16x14 tiles
+const IMAGE_ADDRESS: usize = 0x100;
+
+pub struct PocketCamera {
+    pub rom: Memory,
+    pub ram: Memory,
+    pub sensor: M64282FP,
+
+    ram_enabled: bool,
+    rom_bank: usize,
+    ram_bank: usize,
+    registers_mapped: bool,
+}
+
+impl PocketCamera {
+    pub fn new(rom: &[u8]) -> PocketCamera {
+        let mut r = Memory::new(rom.len());
+        r.write_bytes(0x00, rom);
+
+        PocketCamera {
+            rom: r,
+            ram: Memory::new(RAM_SIZE),
+            sensor: M64282FP::new(),
+            ram_enabled: false,
+            rom_bank: 0x01,
+            ram_bank: 0x00,
+            registers_mapped: false,
+        }
+    }
+
+    fn ram_addr(&self, addr: u16) -> usize {
+        ((self.ram_bank * 0x2000) | (addr as usize & 0x1FFF)) % self.ram.len()
+    }
+
+    // The capture is finished as soon as it's started, so games polling the busy bit
+    // in register 0 see it clear straight away
+    fn capture(&mut self) {
+        let image = self.sensor.capture();
+
+        for y in 0..IMAGE_HEIGHT {
+            for x in 0..IMAGE_WIDTH {
+                let tile = (y / 8) * (IMAGE_WIDTH / 8) + x / 8;
+                let addr = IMAGE_ADDRESS + tile * 0x10 + (y % 8) * 2;
+                let bit = 0x80 >> (x % 8);
+                let shade = image[y * IMAGE_WIDTH + x];
+                let low = if shade & 0x01 == 0x01 { bit } else { 0x00 };
+                let high = if shade & 0x02 == 0x02 { bit } else { 0x00 };
+
+                self.ram[addr] = (self.ram[addr] & !bit) | low;
+                self.ram[addr + 1] = (self.ram[addr + 1] & !bit) | high;
+            }
+        }
+
+        self.sensor.registers[0x00] &= !0x01;
+    }
+}
+
+impl MBC for PocketCamera {
+    fn read_ram_u8(&self, addr: u16) -> u8 {
+        if self.registers_mapped {
+            // The 0x80 bytes of registers are mirrored across the whole window
+            return self.sensor.read_u8(addr as usize & 0x7F);
+        }
+
+        self.ram[self.ram_addr(addr)]
+    }
+
+    fn read_rom_u8(&self, addr: u16) -> u8 {
+        let addr = if addr < 0x4000 {
+            addr as usize
+        } else {
+            self.rom_bank * 0x4000 | (addr as usize & 0x3FFF)
+        };
+
+        self.rom[addr % self.rom.len()]
+    }
+
+    fn write_ram_u8(&mut self, addr: u16, b: u8) {
+        if self.registers_mapped {
+            let register = addr as usize & 0x7F;
+            self.sensor.write_u8(register, b);
+            if register == 0x00 && b & 0x01 == 0x01 {
+                self.capture();
+            }
+        } else if self.ram_enabled {
+            let a = self.ram_addr(addr);
+            self.ram[a] = b;
+        }
+    }
+
+    fn write_rom_u8(&mut self, addr: u16, b: u8) {
+        match addr {
+            0x0000...0x1FFF => self.ram_enabled = (b & 0x0F) == 0x0A,
+            // Unlike the MBCs, bank 0 can be mapped into 0x4000-0x7FFF
+            0x2000...0x3FFF => self.rom_bank = b as usize & 0x3F,
+            0x4000...0x5FFF => {
+                self.registers_mapped = b & 0x10 == 0x10;
+                self.ram_bank = b as usize & 0x0F;
+            }
+            0x6000...0x7FFF => (),
+            _ => panic!("Unsupported address range in Pocket Camera: {:04X}", addr),
+        }
+    }
+
+    fn write_ram_u16(&mut self, addr: u16, b: u16) {
+        self.write_ram_u8(addr, b as u8);
+        self.write_ram_u8(addr + 0x01, (b >> 0x08) as u8);
+    }
+
+    fn write_ram_bank_u8(&mut self, bank: usize, addr: u16, b: u8) {
+        let len = self.ram.len();
+        self.ram[((bank * 0x2000) | (addr as usize & 0x1FFF)) % len] = b;
+    }
+
+    fn save_ram(&self) -> Vec<u8> {
+        self.ram.to_vec()
+    }
+
+    fn load_ram(&mut self, data: &[u8]) {
+        load_ram_from(&mut self.ram, data);
+    }
+
+    fn set_camera_source(&mut self, source: Box<CameraSource>) {
+        self.sensor.source = source;
+    }
+}
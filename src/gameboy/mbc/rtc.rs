@@ -0,0 +1,164 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use byteorder::{ByteOrder, LittleEndian};
+
+const SECONDS_PER_DAY: u64 = 0x15180; // 86,400
+const MAX_DAYS: u64 = 0x200; // The day counter is 9 bits wide
+
+// 5 live registers and 5 latched registers as 32-bit values, followed by a 64-bit timestamp
+pub const RTC_SAVE_SIZE: usize = 0x30;
+
+// Anything that can tell the RTC how many seconds have passed. The emulator uses the
+// host clock, tests can supply their own to step time deterministically.
+pub trait RtcClock {
+    fn now(&self) -> u64;
+}
+
+pub struct SystemClock;
+
+impl RtcClock for SystemClock {
+    fn now(&self) -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct RtcRegisters {
+    pub seconds: u8,
+    pub minutes: u8,
+    pub hours: u8,
+    pub days: u16,
+    pub halted: bool,
+    pub carry: bool,
+}
+
+impl RtcRegisters {
+    pub fn read_u8(&self, register: u8) -> u8 {
+        match register {
+            0x08 => self.seconds,
+            0x09 => self.minutes,
+            0x0A => self.hours,
+            0x0B => self.days as u8,
+            0x0C => {
+                ((self.days >> 0x08) as u8 & 0x01) | (if self.halted { 0x40 } else { 0x00 }) |
+                (if self.carry { 0x80 } else { 0x00 })
+            }
+            _ => 0xFF,
+        }
+    }
+}
+
+pub struct Rtc {
+    pub registers: RtcRegisters,
+    pub latched: RtcRegisters,
+
+    clock: Box<RtcClock>,
+    last_update: u64,
+    latch_armed: bool,
+}
+
+impl Rtc {
+    pub fn new(clock: Box<RtcClock>) -> Rtc {
+        let now = clock.now();
+
+        Rtc {
+            registers: RtcRegisters::default(),
+            latched: RtcRegisters::default(),
+            clock: clock,
+            last_update: now,
+            latch_armed: false,
+        }
+    }
+
+    // Catch the live registers up with however much time has passed since the last access
+    pub fn update(&mut self) {
+        let now = self.clock.now();
+        let elapsed = now.saturating_sub(self.last_update);
+        self.last_update = now;
+
+        if self.registers.halted || elapsed == 0x00 {
+            return;
+        }
+
+        let r = &mut self.registers;
+        let total = r.seconds as u64 + r.minutes as u64 * 60 + r.hours as u64 * 3600 +
+                    r.days as u64 * SECONDS_PER_DAY + elapsed;
+
+        let days = total / SECONDS_PER_DAY;
+        if days >= MAX_DAYS {
+            r.carry = true;
+        }
+
+        r.days = (days % MAX_DAYS) as u16;
+        r.hours = ((total % SECONDS_PER_DAY) / 3600) as u8;
+        r.minutes = ((total % 3600) / 60) as u8;
+        r.seconds = (total % 60) as u8;
+    }
+
+    // Latching is triggered by writing 0x00 followed by 0x01 to 0x6000-0x7FFF
+    pub fn write_latch(&mut self, b: u8) {
+        if self.latch_armed && b == 0x01 {
+            self.update();
+            self.latched = self.registers;
+        }
+
+        self.latch_armed = b == 0x00;
+    }
+
+    pub fn save(&self) -> Vec<u8> {
+        let mut data = vec![0x00; RTC_SAVE_SIZE];
+
+        for (i, register) in (0x08..0x0D).enumerate() {
+            LittleEndian::write_u32(&mut data[i * 4..], self.registers.read_u8(register) as u32);
+            LittleEndian::write_u32(&mut data[0x14 + i * 4..],
+                                    self.latched.read_u8(register) as u32);
+        }
+        LittleEndian::write_u64(&mut data[0x28..], self.last_update);
+
+        data
+    }
+
+    pub fn load(&mut self, data: &[u8]) {
+        if data.len() < RTC_SAVE_SIZE {
+            return;
+        }
+
+        for (i, register) in (0x08..0x0D).enumerate() {
+            let live = LittleEndian::read_u32(&data[i * 4..]) as u8;
+            let latched = LittleEndian::read_u32(&data[0x14 + i * 4..]) as u8;
+            Self::write_register(&mut self.registers, register, live);
+            Self::write_register(&mut self.latched, register, latched);
+        }
+
+        // Catch up on however long the emulator was closed for
+        self.last_update = LittleEndian::read_u64(&data[0x28..]);
+        self.update();
+    }
+
+    pub fn read_u8(&self, register: u8) -> u8 {
+        self.latched.read_u8(register)
+    }
+
+    pub fn write_u8(&mut self, register: u8, b: u8) {
+        self.update();
+        Self::write_register(&mut self.registers, register, b);
+    }
+
+    fn write_register(r: &mut RtcRegisters, register: u8, b: u8) {
+        match register {
+            0x08 => r.seconds = b & 0x3F,
+            0x09 => r.minutes = b & 0x3F,
+            0x0A => r.hours = b & 0x1F,
+            0x0B => r.days = (r.days & 0x100) | b as u16,
+            0x0C => {
+                r.days = (r.days & 0xFF) | ((b as u16 & 0x01) << 0x08);
+                r.halted = b & 0x40 == 0x40;
+                r.carry = b & 0x80 == 0x80;
+            }
+            _ => (),
+        }
+    }
+}
@@ -1,33 +1,54 @@
 #[macro_use]
 mod macros;
 
+mod archive;
 mod cartridge;
+mod cartridge_details;
+mod cartridge_error;
+mod cheat;
 mod cpu;
+mod crc32;
 mod disassembler;
+mod dma;
 pub mod debugger;
 mod gameboy;
 pub mod gfx;
+mod hardware_model;
 mod interconnect;
 mod irq;
 mod joypad;
+mod licensee;
 mod mbc;
 mod memory;
 mod memory_map;
+mod patch;
 pub mod opcodes;
 mod registers;
+mod romdb;
 mod serial;
+mod sha1;
 mod timer;
 pub mod ui;
 
-pub use self::cartridge::{Cartridge, CartridgeDetails};
+pub use self::archive::{read_rom, ArchiveError};
+pub use self::cartridge::Cartridge;
+pub use self::cartridge_details::{CartridgeDetails, CartridgeKind, CgbSupport, Destination, Mapper};
+pub use self::cartridge_error::CartridgeError;
+pub use self::cheat::{Cheat, CheatCode, CheatError};
 pub use self::cpu::{Cpu, CpuSpeed};
 pub use self::disassembler::disassemble;
-pub use self::gfx::Frame;
+pub use self::gfx::{Frame, Renderer};
 pub use self::gameboy::GameBoy;
+pub use self::hardware_model::HardwareModel;
 pub use self::joypad::{Joypad, JoypadButton};
+pub use self::mbc::{BlankSource, CameraSource, HuC1, HuC3, ImageSource, IrTransceiver,
+                    LoopbackTransceiver, NullTransceiver, MBC, MBC3, MBC5, MBC7, PocketCamera,
+                    RtcClock, SystemClock};
 pub use self::memory::Memory;
+pub use self::patch::{apply_patch, find_adjacent_patch, PatchError, PatchFormat};
 pub use self::interconnect::Interconnect;
 pub use self::irq::{Interrupt, Irq};
+pub use self::romdb::{DatError, DumpStatus, RomDatabase, RomEntry};
 pub use self::timer::Timer;
 pub use self::ui::ui::Ui;
 
@@ -35,6 +56,7 @@ pub use self::macros::*;
 
 pub const CPU_FREQUENCY: usize = 0x400000; // 4,194,304
 pub const MAX_CPU_CYCLES: usize = CPU_FREQUENCY / 60; // 60hz, our target refresh rate/fps
+pub const M_CYCLE: usize = 0x04; // Every memory access takes one M-cycle, 4 clock cycles
 pub const MAX_DIV_REG_CYCLES: usize = MAX_CPU_CYCLES / 0x10;
 
 pub const SCREEN_WIDTH: usize = 0xA0;
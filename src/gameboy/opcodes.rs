@@ -40,4038 +40,4592 @@ pub struct OpCode {
     pub code: u8,
     pub mnemonic: &'static str,
     pub length: u16,
+    // For conditional branches, cycles is the cost when the branch isn't taken
     pub cycles: u8,
+    pub cycles_taken: u8,
     pub argument_type: ArgumentType,
     pub extended: bool,
 }
 
 impl OpCode {
-    pub fn from_byte<'opcode>(byte: u8, extended: bool) -> Option<&'opcode OpCode> {
+    pub fn from_byte(byte: u8, extended: bool) -> Option<&'static OpCode> {
         if extended {
-            EXT_OPCODES.iter().find(|opcode| opcode.code == byte)
+            Some(&EXT_OPCODES[byte as usize])
         } else {
-            OPCODES.iter().find(|opcode| opcode.code == byte)
+            OPCODES[byte as usize].as_ref()
         }
     }
 
     pub fn from_mnemonic<S>(input: S) -> Option<OpCode>
         where S: Into<String>
     {
-        let input = input.into();
+        let input = input.into().to_uppercase();
         OPCODES.iter()
-            .find(|opcode| opcode.mnemonic == input.to_uppercase())
+            .filter_map(|opcode| opcode.as_ref())
+            .find(|opcode| opcode.mnemonic == input)
             .cloned()
     }
 }
 
-static OPCODES: [OpCode; 245] = [OpCode {
-                                     code: 0x00,
-                                     mnemonic: "NOP",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x01,
-                                     mnemonic: "LD BC, {imm16}",
-                                     length: 3,
-                                     cycles: 12,
-                                     argument_type: ArgumentType::Imm16,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x02,
-                                     mnemonic: "LD (BC), A",
-                                     length: 1,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x03,
-                                     mnemonic: "INC BC",
-                                     length: 1,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x04,
-                                     mnemonic: "INC B",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x05,
-                                     mnemonic: "DEC B",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x06,
-                                     mnemonic: "LD B, {imm8}",
-                                     length: 2,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Imm8,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x07,
-                                     mnemonic: "RCLA",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x08,
-                                     mnemonic: "LD ({imm16}), SP",
-                                     length: 3,
-                                     cycles: 20,
-                                     argument_type: ArgumentType::Imm16,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x09,
-                                     mnemonic: "ADD HL, BC",
-                                     length: 1,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x0A,
-                                     mnemonic: "LD A, (BC)",
-                                     length: 1,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x0B,
-                                     mnemonic: "DEC BC",
-                                     length: 1,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x0C,
-                                     mnemonic: "INC C",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x0D,
-                                     mnemonic: "DEC C",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x0E,
-                                     mnemonic: "LD C, {imm8}",
-                                     length: 2,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Imm8,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x0F,
-                                     mnemonic: "RRCA",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x10,
-                                     mnemonic: "STOP",
-                                     length: 2, // theres a $00 after this opcode
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x11,
-                                     mnemonic: "LD DE, {imm16}",
-                                     length: 3,
-                                     cycles: 12,
-                                     argument_type: ArgumentType::Imm16,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x12,
-                                     mnemonic: "LD (DE), A",
-                                     length: 1,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x13,
-                                     mnemonic: "INC DE",
-                                     length: 1,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x14,
-                                     mnemonic: "INC D",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x15,
-                                     mnemonic: "DEC D",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x16,
-                                     mnemonic: "LD D, {imm8}",
-                                     length: 2,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Imm8,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x17,
-                                     mnemonic: "RLA",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x18,
-                                     mnemonic: "JP {imm8}",
-                                     length: 2,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Imm8,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x19,
-                                     mnemonic: "ADD HL, DE",
-                                     length: 1,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x1A,
-                                     mnemonic: "LD A, (DE)",
-                                     length: 1,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x1B,
-                                     mnemonic: "DEC DE",
-                                     length: 1,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x1C,
-                                     mnemonic: "INC E",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x1D,
-                                     mnemonic: "DEC E",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x1E,
-                                     mnemonic: "LD E, {imm8}",
-                                     length: 2,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Imm8,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x1F,
-                                     mnemonic: "RRA",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x20,
-                                     mnemonic: "JR NZ, {imm8}",
-                                     length: 2,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Imm8,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x21,
-                                     mnemonic: "LD HL, {imm16}",
-                                     length: 3,
-                                     cycles: 12,
-                                     argument_type: ArgumentType::Imm16,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x22,
-                                     mnemonic: "LD (HLI), A",
-                                     length: 1,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x23,
-                                     mnemonic: "INC HL",
-                                     length: 1,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x24,
-                                     mnemonic: "INC H",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x25,
-                                     mnemonic: "DEC H",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x26,
-                                     mnemonic: "LD H, {imm8}",
-                                     length: 2,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Imm8,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x27,
-                                     mnemonic: "DAA",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x28,
-                                     mnemonic: "JR Z, {imm8}",
-                                     length: 2,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Imm8,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x29,
-                                     mnemonic: "ADD HL, HL",
-                                     length: 1,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x2A,
-                                     mnemonic: "LD A, (HLI)",
-                                     length: 1,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x2B,
-                                     mnemonic: "DEC HL",
-                                     length: 1,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x2C,
-                                     mnemonic: "INC L",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x2D,
-                                     mnemonic: "DEC L",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x2E,
-                                     mnemonic: "LD L, {imm8}",
-                                     length: 2,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Imm8,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x2F,
-                                     mnemonic: "CPL",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x30,
-                                     mnemonic: "JR NC, {imm8}",
-                                     length: 2,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Imm8,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x31,
-                                     mnemonic: "LD SP, {imm16}",
-                                     length: 3,
-                                     cycles: 12,
-                                     argument_type: ArgumentType::Imm16,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x32,
-                                     mnemonic: "LD (HLD), A",
-                                     length: 1,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x33,
-                                     mnemonic: "INC SP",
-                                     length: 1,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x34,
-                                     mnemonic: "INC (HL)",
-                                     length: 1,
-                                     cycles: 12,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x35,
-                                     mnemonic: "DEC (HL)",
-                                     length: 1,
-                                     cycles: 12,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x36,
-                                     mnemonic: "LD (HL), {imm8}",
-                                     length: 2,
-                                     cycles: 12,
-                                     argument_type: ArgumentType::Imm8,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x37,
-                                     mnemonic: "SCF",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x38,
-                                     mnemonic: "JR C, {imm8}",
-                                     length: 2,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Imm8,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x39,
-                                     mnemonic: "ADD HL, SP",
-                                     length: 1,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x3A,
-                                     mnemonic: "LD A, (HLD)",
-                                     length: 1,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x3B,
-                                     mnemonic: "DEC SP",
-                                     length: 1,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x3C,
-                                     mnemonic: "INC A",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x3D,
-                                     mnemonic: "DEC A",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x3E,
-                                     mnemonic: "LD A, {imm8}",
-                                     length: 2,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Imm8,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x3F,
-                                     mnemonic: "CCF",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x40,
-                                     mnemonic: "LD B, B",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x41,
-                                     mnemonic: "LD B, C",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x42,
-                                     mnemonic: "LD B, D",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x43,
-                                     mnemonic: "LD B, E",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x44,
-                                     mnemonic: "LD B, H",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x45,
-                                     mnemonic: "LD B, L",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x46,
-                                     mnemonic: "LD B, (HL)",
-                                     length: 1,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x47,
-                                     mnemonic: "LD B, A",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x48,
-                                     mnemonic: "LD C, B",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x49,
-                                     mnemonic: "LD C, C",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x4A,
-                                     mnemonic: "LD C, D",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x4B,
-                                     mnemonic: "LD C, E",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x4C,
-                                     mnemonic: "LD C, H",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x4D,
-                                     mnemonic: "LD C, L",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x4E,
-                                     mnemonic: "LD C, (HL)",
-                                     length: 1,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x4F,
-                                     mnemonic: "LD C, A",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x50,
-                                     mnemonic: "LD D, B",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x51,
-                                     mnemonic: "LD D, C",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x52,
-                                     mnemonic: "LD D, D",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x53,
-                                     mnemonic: "LD D, E",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x54,
-                                     mnemonic: "LD D, H",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x55,
-                                     mnemonic: "LD D, L",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x56,
-                                     mnemonic: "LD D, (HL)",
-                                     length: 1,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x57,
-                                     mnemonic: "LD D, A",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x58,
-                                     mnemonic: "LD E, B",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x59,
-                                     mnemonic: "LD E, C",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x5A,
-                                     mnemonic: "LD E, D",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x5B,
-                                     mnemonic: "LD E, E",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x5C,
-                                     mnemonic: "LD E, H",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x5D,
-                                     mnemonic: "LD E, L",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x5E,
-                                     mnemonic: "LD E, (HL)",
-                                     length: 1,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x5F,
-                                     mnemonic: "LD E, A",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x60,
-                                     mnemonic: "LD H, B",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x61,
-                                     mnemonic: "LD H, C",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x62,
-                                     mnemonic: "LD H, D",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x63,
-                                     mnemonic: "LD H, E",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x64,
-                                     mnemonic: "LD H, H",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x65,
-                                     mnemonic: "LD H, L",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x66,
-                                     mnemonic: "LD H, (HL)",
-                                     length: 1,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x67,
-                                     mnemonic: "LD H, A",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x68,
-                                     mnemonic: "LD L, B",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x69,
-                                     mnemonic: "LD L, C",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x6A,
-                                     mnemonic: "LD L, D",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x6B,
-                                     mnemonic: "LD L, E",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x6C,
-                                     mnemonic: "LD L, H",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x6D,
-                                     mnemonic: "LD L, L",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x6E,
-                                     mnemonic: "LD L, (HL)",
-                                     length: 1,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x6F,
-                                     mnemonic: "LD L, A",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x70,
-                                     mnemonic: "LD (HL), B",
-                                     length: 1,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x71,
-                                     mnemonic: "LD (HL), C",
-                                     length: 1,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x72,
-                                     mnemonic: "LD (HL), D",
-                                     length: 1,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x73,
-                                     mnemonic: "LD (HL), E",
-                                     length: 1,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x74,
-                                     mnemonic: "LD (HL), H",
-                                     length: 1,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x75,
-                                     mnemonic: "LD (HL), L",
-                                     length: 1,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x76,
-                                     mnemonic: "HALT",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x77,
-                                     mnemonic: "LD (HL), A",
-                                     length: 1,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x78,
-                                     mnemonic: "LD A, B",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x79,
-                                     mnemonic: "LD A, C",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x7A,
-                                     mnemonic: "LD A, D",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x7B,
-                                     mnemonic: "LD A, E",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x7C,
-                                     mnemonic: "LD A, H",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x7D,
-                                     mnemonic: "LD A, L",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x7E,
-                                     mnemonic: "LD A, (HL)",
-                                     length: 1,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x7F,
-                                     mnemonic: "LD A, A",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x80,
-                                     mnemonic: "ADD A, B",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x81,
-                                     mnemonic: "ADD A, C",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x82,
-                                     mnemonic: "ADD A, D",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x83,
-                                     mnemonic: "ADD A, E",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x84,
-                                     mnemonic: "ADD A, H",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x85,
-                                     mnemonic: "ADD A, L",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x86,
-                                     mnemonic: "ADD A, (HL)",
-                                     length: 1,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x87,
-                                     mnemonic: "AND A, A",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x88,
-                                     mnemonic: "ADC A, B",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x89,
-                                     mnemonic: "ADC A, C",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x8A,
-                                     mnemonic: "ADC A, D",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x8B,
-                                     mnemonic: "ADC A, E",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x8C,
-                                     mnemonic: "ADC A, H",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x8D,
-                                     mnemonic: "ADC A, L",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x8E,
-                                     mnemonic: "ADC A, (HL)",
-                                     length: 1,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x8F,
-                                     mnemonic: "ADC A, A",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x90,
-                                     mnemonic: "SUB B",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x91,
-                                     mnemonic: "SUB C",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x92,
-                                     mnemonic: "SUB D",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x93,
-                                     mnemonic: "SUB E",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x94,
-                                     mnemonic: "SUB H",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x95,
-                                     mnemonic: "SUB L",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x96,
-                                     mnemonic: "SUB (HL)",
-                                     length: 1,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x97,
-                                     mnemonic: "SUB A",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x98,
-                                     mnemonic: "SBC A, B",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x99,
-                                     mnemonic: "SBC A, C",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x9A,
-                                     mnemonic: "SBC A, D",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x9B,
-                                     mnemonic: "SBC A, E",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x9C,
-                                     mnemonic: "SBC A, H",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x9D,
-                                     mnemonic: "SBC A, L",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x9E,
-                                     mnemonic: "SBC A, (HL)",
-                                     length: 1,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0x9F,
-                                     mnemonic: "SBC A, A",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xA0,
-                                     mnemonic: "AND B",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xA1,
-                                     mnemonic: "AND C",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xA2,
-                                     mnemonic: "AND D",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xA3,
-                                     mnemonic: "AND E",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xA4,
-                                     mnemonic: "AND H",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xA5,
-                                     mnemonic: "AND L",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xA6,
-                                     mnemonic: "AND (HL)",
-                                     length: 1,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xA7,
-                                     mnemonic: "AND A",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xA8,
-                                     mnemonic: "XOR B",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xA9,
-                                     mnemonic: "XOR C",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xAA,
-                                     mnemonic: "XOR D",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xAB,
-                                     mnemonic: "XOR E",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xAC,
-                                     mnemonic: "XOR H",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xAD,
-                                     mnemonic: "XOR L",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xAE,
-                                     mnemonic: "XOR (HL)",
-                                     length: 1,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xAF,
-                                     mnemonic: "XOR A",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xB0,
-                                     mnemonic: "OR B",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xB1,
-                                     mnemonic: "OR C",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xB2,
-                                     mnemonic: "OR D",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xB3,
-                                     mnemonic: "OR E",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xB4,
-                                     mnemonic: "OR H",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xB5,
-                                     mnemonic: "OR L",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xB6,
-                                     mnemonic: "OR (HL)",
-                                     length: 1,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xB7,
-                                     mnemonic: "OR A",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xB8,
-                                     mnemonic: "CP B",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xB9,
-                                     mnemonic: "CP C",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xBA,
-                                     mnemonic: "CP D",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xBB,
-                                     mnemonic: "CP E",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xBC,
-                                     mnemonic: "CP H",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xBD,
-                                     mnemonic: "CP L",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xBE,
-                                     mnemonic: "CP (HL)",
-                                     length: 1,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xBF,
-                                     mnemonic: "CP A",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xC0,
-                                     mnemonic: "RET NZ",
-                                     length: 1,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xC1,
-                                     mnemonic: "POP BC",
-                                     length: 1,
-                                     cycles: 12,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xC2,
-                                     mnemonic: "JP NZ, {imm16}",
-                                     length: 3,
-                                     cycles: 12,
-                                     argument_type: ArgumentType::Imm16,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xC3,
-                                     mnemonic: "JP {imm16}",
-                                     length: 3,
-                                     cycles: 12,
-                                     argument_type: ArgumentType::Imm16,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xC4,
-                                     mnemonic: "CALL NZ, {imm16}",
-                                     length: 3,
-                                     cycles: 12,
-                                     argument_type: ArgumentType::Imm16,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xC5,
-                                     mnemonic: "PUSH BC",
-                                     length: 1,
-                                     cycles: 16,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xC6,
-                                     mnemonic: "ADD A, {imm8}",
-                                     length: 2,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Imm8,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xC7,
-                                     mnemonic: "RST 0x00",
-                                     length: 1,
-                                     cycles: 32,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xC8,
-                                     mnemonic: "RET Z",
-                                     length: 1,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xC9,
-                                     mnemonic: "RET",
-                                     length: 1,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xCA,
-                                     mnemonic: "JP Z, {imm16}",
-                                     length: 3,
-                                     cycles: 12,
-                                     argument_type: ArgumentType::Imm16,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xCB,
-                                     mnemonic: "",
-                                     length: 1,
-                                     cycles: 0,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xCC,
-                                     mnemonic: "CALL Z, {imm16}",
-                                     length: 3,
-                                     cycles: 12,
-                                     argument_type: ArgumentType::Imm16,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xCD,
-                                     mnemonic: "CALL {imm16}",
-                                     length: 3,
-                                     cycles: 12,
-                                     argument_type: ArgumentType::Imm16,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xCE,
-                                     mnemonic: "ADC A, {imm8}",
-                                     length: 2,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Imm8,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xCF,
-                                     mnemonic: "RST 0x08",
-                                     length: 1,
-                                     cycles: 32,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xD0,
-                                     mnemonic: "RET NC",
-                                     length: 1,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xD1,
-                                     mnemonic: "POP DE",
-                                     length: 1,
-                                     cycles: 12,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xD2,
-                                     mnemonic: "JP NC, {imm16}",
-                                     length: 3,
-                                     cycles: 12,
-                                     argument_type: ArgumentType::Imm16,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xD4,
-                                     mnemonic: "CALL NC, {imm16}",
-                                     length: 3,
-                                     cycles: 12,
-                                     argument_type: ArgumentType::Imm16,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xD5,
-                                     mnemonic: "PUSH DE",
-                                     length: 1,
-                                     cycles: 16,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xD6,
-                                     mnemonic: "SUB {imm8}",
-                                     length: 2,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Imm8,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xD7,
-                                     mnemonic: "RST 0x10",
-                                     length: 1,
-                                     cycles: 32,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xD8,
-                                     mnemonic: "RET C",
-                                     length: 1,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xD9,
-                                     mnemonic: "RETI",
-                                     length: 1,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xDA,
-                                     mnemonic: "JP C, {imm16}",
-                                     length: 3,
-                                     cycles: 12,
-                                     argument_type: ArgumentType::Imm16,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xDC,
-                                     mnemonic: "CALL C, {imm16}",
-                                     length: 3,
-                                     cycles: 12,
-                                     argument_type: ArgumentType::Imm16,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xDE,
-                                     mnemonic: "SBC A, {imm8}",
-                                     length: 2,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Imm8,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xDF,
-                                     mnemonic: "RST 0x18",
-                                     length: 1,
-                                     cycles: 32,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xE0,
-                                     mnemonic: "LD ($FF00+{imm8}), A",
-                                     length: 2,
-                                     cycles: 12,
-                                     argument_type: ArgumentType::Imm8,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xE1,
-                                     mnemonic: "POP HL",
-                                     length: 1,
-                                     cycles: 12,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xE2,
-                                     mnemonic: "LD ($FF00+C), A",
-                                     length: 1,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xE5,
-                                     mnemonic: "PUSH HL",
-                                     length: 1,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xE6,
-                                     mnemonic: "AND {imm8}",
-                                     length: 2,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Imm8,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xE7,
-                                     mnemonic: "RST 0x20",
-                                     length: 1,
-                                     cycles: 32,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xE8,
-                                     mnemonic: "ADD SP, {imm8}",
-                                     length: 2,
-                                     cycles: 16,
-                                     argument_type: ArgumentType::Imm8,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xE9,
-                                     mnemonic: "JP (HL)",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xEA,
-                                     mnemonic: "LD ({imm16}), A",
-                                     length: 3,
-                                     cycles: 16,
-                                     argument_type: ArgumentType::Imm16,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xEE,
-                                     mnemonic: "XOR A, {imm8}",
-                                     length: 2,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Imm8,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xEF,
-                                     mnemonic: "RST 0x28",
-                                     length: 1,
-                                     cycles: 32,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xF0,
-                                     mnemonic: "LD A, ($FF00+{imm8})",
-                                     length: 2,
-                                     cycles: 12,
-                                     argument_type: ArgumentType::Imm8,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xF1,
-                                     mnemonic: "POP AF",
-                                     length: 1,
-                                     cycles: 12,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xF2,
-                                     mnemonic: "LD A, (C)",
-                                     length: 1,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xF3,
-                                     mnemonic: "DI",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xF5,
-                                     mnemonic: "PUSH AF",
-                                     length: 1,
-                                     cycles: 16,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xF6,
-                                     mnemonic: "OR {imm8}",
-                                     length: 2,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Imm8,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xF7,
-                                     mnemonic: "RST 0x30",
-                                     length: 1,
-                                     cycles: 32,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xF8,
-                                     mnemonic: "LDHL SP, {imm8}",
-                                     length: 2,
-                                     cycles: 12,
-                                     argument_type: ArgumentType::Imm8,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xF9,
-                                     mnemonic: "LD SP, HL",
-                                     length: 1,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xFA,
-                                     mnemonic: "LD A, ({imm16})",
-                                     length: 3,
-                                     cycles: 16,
-                                     argument_type: ArgumentType::Imm16,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xFB,
-                                     mnemonic: "EI",
-                                     length: 1,
-                                     cycles: 4,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xFE,
-                                     mnemonic: "CP {imm8}",
-                                     length: 2,
-                                     cycles: 8,
-                                     argument_type: ArgumentType::Imm8,
-                                     extended: false,
-                                 },
-                                 OpCode {
-                                     code: 0xFF,
-                                     mnemonic: "RST 0x38",
-                                     length: 1,
-                                     cycles: 32,
-                                     argument_type: ArgumentType::Implied,
-                                     extended: false,
-                                 }];
+static OPCODES: [Option<OpCode>; 256] = OPCODE_TABLE;
+static EXT_OPCODES: [OpCode; 256] = EXT_OPCODE_TABLE;
 
-// EXTENDED OPCODES (all prefixed with 0xCB)
+// from_byte trusts that every entry sits at the index of its own code, so a table
+// edited out of order fails to compile rather than decoding the wrong instruction
+const _: () = assert!(table_in_order(&OPCODE_TABLE), "OPCODE_TABLE is out of order");
+const _: () = assert!(ext_table_in_order(&EXT_OPCODE_TABLE), "EXT_OPCODE_TABLE is out of order");
+
+const fn table_in_order(table: &[Option<OpCode>; 256]) -> bool {
+    let mut i = 0;
+    while i < 256 {
+        if let Some(ref opcode) = table[i] {
+            if opcode.code as usize != i || opcode.extended {
+                return false;
+            }
+        }
+        i += 1;
+    }
 
-static EXT_OPCODES: [OpCode; 256] = [OpCode {
-                                         code: 0x00,
-                                         mnemonic: "RLC B",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: false,
-                                     },
-                                     OpCode {
-                                         code: 0x01,
-                                         mnemonic: "RLC C",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: false,
-                                     },
-                                     OpCode {
-                                         code: 0x02,
-                                         mnemonic: "RLC D",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: false,
-                                     },
-                                     OpCode {
-                                         code: 0x03,
-                                         mnemonic: "RLC E",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: false,
-                                     },
-                                     OpCode {
-                                         code: 0x04,
-                                         mnemonic: "RLC H",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: false,
-                                     },
-                                     OpCode {
-                                         code: 0x05,
-                                         mnemonic: "RLC L",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: false,
-                                     },
-                                     OpCode {
-                                         code: 0x06,
-                                         mnemonic: "RLC (HL)",
-                                         length: 1,
-                                         cycles: 16,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: false,
-                                     },
-                                     OpCode {
-                                         code: 0x07,
-                                         mnemonic: "RLC A",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: false,
-                                     },
-                                     OpCode {
-                                         code: 0x08,
-                                         mnemonic: "RRC B",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: false,
-                                     },
-                                     OpCode {
-                                         code: 0x09,
-                                         mnemonic: "RRC C",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: false,
-                                     },
-                                     OpCode {
-                                         code: 0x0A,
-                                         mnemonic: "RRC D",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: false,
-                                     },
-                                     OpCode {
-                                         code: 0x0B,
-                                         mnemonic: "RRC E",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: false,
-                                     },
-                                     OpCode {
-                                         code: 0x0C,
-                                         mnemonic: "RRC H",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: false,
-                                     },
-                                     OpCode {
-                                         code: 0x0D,
-                                         mnemonic: "RRC L",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: false,
-                                     },
-                                     OpCode {
-                                         code: 0x0E,
-                                         mnemonic: "RRC (HL)",
-                                         length: 1,
-                                         cycles: 16,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: false,
-                                     },
-                                     OpCode {
-                                         code: 0x0F,
-                                         mnemonic: "RRC A",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: false,
-                                     },
-                                     OpCode {
-                                         code: 0x10,
-                                         mnemonic: "RL B",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x11,
-                                         mnemonic: "RL C",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x12,
-                                         mnemonic: "RL D",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x13,
-                                         mnemonic: "RL E",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x14,
-                                         mnemonic: "RL H",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x15,
-                                         mnemonic: "RL L",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x16,
-                                         mnemonic: "RL (HL)",
-                                         length: 1,
-                                         cycles: 16,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x17,
-                                         mnemonic: "RL A",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x18,
-                                         mnemonic: "RR B",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x19,
-                                         mnemonic: "RR C",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x1A,
-                                         mnemonic: "RR D",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x1B,
-                                         mnemonic: "RR E",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x1C,
-                                         mnemonic: "RR H",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x1D,
-                                         mnemonic: "RR L",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x1E,
-                                         mnemonic: "RR (HL)",
-                                         length: 1,
-                                         cycles: 16,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x1F,
-                                         mnemonic: "RR A",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x20,
-                                         mnemonic: "SLA B",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x21,
-                                         mnemonic: "SLA C",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x22,
-                                         mnemonic: "SLA D",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x23,
-                                         mnemonic: "SLA E",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x24,
-                                         mnemonic: "SLA H",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x25,
-                                         mnemonic: "SLA L",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x26,
-                                         mnemonic: "SLA (HL)",
-                                         length: 1,
-                                         cycles: 16,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x27,
-                                         mnemonic: "SLA A",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x28,
-                                         mnemonic: "SRA B",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x29,
-                                         mnemonic: "SRA C",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x2A,
-                                         mnemonic: "SRA D",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x2B,
-                                         mnemonic: "SRA E",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x2C,
-                                         mnemonic: "SRA H",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x2D,
-                                         mnemonic: "SRA L",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x2E,
-                                         mnemonic: "SRA (HL)",
-                                         length: 1,
-                                         cycles: 16,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x2F,
-                                         mnemonic: "SRA A",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x30,
-                                         mnemonic: "SWAP B",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x31,
-                                         mnemonic: "SWAP C",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x32,
-                                         mnemonic: "SWAP D",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x33,
-                                         mnemonic: "SWAP E",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x34,
-                                         mnemonic: "SWAP H",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x35,
-                                         mnemonic: "SWAP L",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x36,
-                                         mnemonic: "SWAP (HL)",
-                                         length: 1,
-                                         cycles: 16,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x37,
-                                         mnemonic: "SWAP A",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x38,
-                                         mnemonic: "SRL B",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Imm16,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x39,
-                                         mnemonic: "SRL C",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Imm16,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x3A,
-                                         mnemonic: "SRL D",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Imm16,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x3B,
-                                         mnemonic: "SRL E",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Imm16,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x3C,
-                                         mnemonic: "SRL H",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Imm16,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x3D,
-                                         mnemonic: "SRL L",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Imm16,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x3E,
-                                         mnemonic: "SRL (HL)",
-                                         length: 1,
-                                         cycles: 16,
-                                         argument_type: ArgumentType::Imm16,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x3F,
-                                         mnemonic: "SRL A",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x40,
-                                         mnemonic: "BIT 0, B",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x41,
-                                         mnemonic: "BIT 0, C",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x42,
-                                         mnemonic: "BIT 0, D",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x43,
-                                         mnemonic: "BIT 0, E",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x44,
-                                         mnemonic: "BIT 0, H",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x45,
-                                         mnemonic: "BIT 0, L",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x46,
-                                         mnemonic: "BIT 0, (HL)",
-                                         length: 1,
-                                         cycles: 16,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x47,
-                                         mnemonic: "BIT 0, A",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x48,
-                                         mnemonic: "BIT 1, B",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x49,
-                                         mnemonic: "BIT 1, C",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x4A,
-                                         mnemonic: "BIT 1, D",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x4B,
-                                         mnemonic: "BIT 1, E",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x4C,
-                                         mnemonic: "BIT 1, H",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x4D,
-                                         mnemonic: "BIT 1, L",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x4E,
-                                         mnemonic: "BIT 1, (HL)",
-                                         length: 1,
-                                         cycles: 16,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x4F,
-                                         mnemonic: "BIT 1, A",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x50,
-                                         mnemonic: "BIT 2, B",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x51,
-                                         mnemonic: "BIT 2, C",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x52,
-                                         mnemonic: "BIT 2, D",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x53,
-                                         mnemonic: "BIT 2, E",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x54,
-                                         mnemonic: "BIT 2, H",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x55,
-                                         mnemonic: "BIT 2, L",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x56,
-                                         mnemonic: "BIT 2, (HL)",
-                                         length: 1,
-                                         cycles: 16,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x57,
-                                         mnemonic: "BIT 2, A",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x58,
-                                         mnemonic: "BIT 3, B",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x59,
-                                         mnemonic: "BIT 3, C",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x5A,
-                                         mnemonic: "BIT 3, D",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x5B,
-                                         mnemonic: "BIT 3, E",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x5C,
-                                         mnemonic: "BIT 3, H",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x5D,
-                                         mnemonic: "BIT 3, L",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x5E,
-                                         mnemonic: "BIT 3, (HL)",
-                                         length: 1,
-                                         cycles: 16,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x5F,
-                                         mnemonic: "BIT 3, A",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x60,
-                                         mnemonic: "BIT 4, B",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x61,
-                                         mnemonic: "BIT 4, C",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x62,
-                                         mnemonic: "BIT 4, D",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x63,
-                                         mnemonic: "BIT 4, E",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x64,
-                                         mnemonic: "BIT 4, H",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x65,
-                                         mnemonic: "BIT 4, L",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x66,
-                                         mnemonic: "BIT 4, (HL)",
-                                         length: 1,
-                                         cycles: 16,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x67,
-                                         mnemonic: "BIT 4, A",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x68,
-                                         mnemonic: "BIT 5, B",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x69,
-                                         mnemonic: "BIT 5, C",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x6A,
-                                         mnemonic: "BIT 5, D",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x6B,
-                                         mnemonic: "BIT 5, E",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x6C,
-                                         mnemonic: "BIT 5, H",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x6D,
-                                         mnemonic: "BIT 5, L",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x6E,
-                                         mnemonic: "BIT 5, (HL)",
-                                         length: 1,
-                                         cycles: 16,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x6F,
-                                         mnemonic: "BIT 5, A",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x70,
-                                         mnemonic: "BIT 6, B",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x71,
-                                         mnemonic: "BIT 6, C",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x72,
-                                         mnemonic: "BIT 6, D",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x73,
-                                         mnemonic: "BIT 6, E",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x74,
-                                         mnemonic: "BIT 6, H",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x75,
-                                         mnemonic: "BIT 6, L",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x76,
-                                         mnemonic: "BIT 6, (HL)",
-                                         length: 1,
-                                         cycles: 16,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x77,
-                                         mnemonic: "BIT 6, A",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x78,
-                                         mnemonic: "BIT 7, B",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x79,
-                                         mnemonic: "BIT 7, C",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x7A,
-                                         mnemonic: "BIT 7, D",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x7B,
-                                         mnemonic: "BIT 7, E",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x7C,
-                                         mnemonic: "BIT 7, H",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x7D,
-                                         mnemonic: "BIT 7, L",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x7E,
-                                         mnemonic: "BIT 7, (HL)",
-                                         length: 1,
-                                         cycles: 16,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x7F,
-                                         mnemonic: "BIT 7, A",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x80,
-                                         mnemonic: "RES 0, B",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x81,
-                                         mnemonic: "RES 0, C",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x82,
-                                         mnemonic: "RES 0, D",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x83,
-                                         mnemonic: "RES 0, E",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x84,
-                                         mnemonic: "RES 0, H",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x85,
-                                         mnemonic: "RES 0, L",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x86,
-                                         mnemonic: "RES 0, (HL)",
-                                         length: 1,
-                                         cycles: 16,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x87,
-                                         mnemonic: "RES 0, A",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x88,
-                                         mnemonic: "RES 1, B",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x89,
-                                         mnemonic: "RES 1, C",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x8A,
-                                         mnemonic: "RES 1, D",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x8B,
-                                         mnemonic: "RES 1, E",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x8C,
-                                         mnemonic: "RES 1, H",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x8D,
-                                         mnemonic: "RES 1, L",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x8E,
-                                         mnemonic: "RES 1, (HL)",
-                                         length: 1,
-                                         cycles: 16,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x8F,
-                                         mnemonic: "RES 1, A",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x90,
-                                         mnemonic: "RES 2, B",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x91,
-                                         mnemonic: "RES 2, C",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x92,
-                                         mnemonic: "RES 2, D",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x93,
-                                         mnemonic: "RES 2, E",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x94,
-                                         mnemonic: "RES 2, H",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x95,
-                                         mnemonic: "RES 2, L",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x96,
-                                         mnemonic: "RES 2, (HL)",
-                                         length: 1,
-                                         cycles: 16,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x97,
-                                         mnemonic: "RES 2, A",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x98,
-                                         mnemonic: "RES 3, B",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x99,
-                                         mnemonic: "RES 3, C",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x9A,
-                                         mnemonic: "RES 3, D",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x9B,
-                                         mnemonic: "RES 3, E",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x9C,
-                                         mnemonic: "RES 3, H",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x9D,
-                                         mnemonic: "RES 3, L",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x9E,
-                                         mnemonic: "RES 3, (HL)",
-                                         length: 1,
-                                         cycles: 16,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0x9F,
-                                         mnemonic: "RES 3, A",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xA0,
-                                         mnemonic: "RES 4, B",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xA1,
-                                         mnemonic: "RES 4, C",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xA2,
-                                         mnemonic: "RES 4, D",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xA3,
-                                         mnemonic: "RES 4, E",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xA4,
-                                         mnemonic: "RES 4, H",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xA5,
-                                         mnemonic: "RES 4, L",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xA6,
-                                         mnemonic: "RES 4, (HL)",
-                                         length: 1,
-                                         cycles: 16,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xA7,
-                                         mnemonic: "RES 4, A",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xA8,
-                                         mnemonic: "RES 5, B",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xA9,
-                                         mnemonic: "RES 5, C",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xAA,
-                                         mnemonic: "RES 5, D",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xAB,
-                                         mnemonic: "RES 5, E",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xAC,
-                                         mnemonic: "RES 5, H",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xAD,
-                                         mnemonic: "RES 5, L",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xAE,
-                                         mnemonic: "RES 5, (HL)",
-                                         length: 1,
-                                         cycles: 16,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xAF,
-                                         mnemonic: "RES 5, A",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xB0,
-                                         mnemonic: "RES 6, B",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xB1,
-                                         mnemonic: "RES 6, C",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xB2,
-                                         mnemonic: "RES 6, D",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xB3,
-                                         mnemonic: "RES 6, E",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xB4,
-                                         mnemonic: "RES 6, H",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xB5,
-                                         mnemonic: "RES 6, L",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xB6,
-                                         mnemonic: "RES 6, (HL)",
-                                         length: 1,
-                                         cycles: 16,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xB7,
-                                         mnemonic: "RES 6, A",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xB8,
-                                         mnemonic: "RES 7, B",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xB9,
-                                         mnemonic: "RES 7, C",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xBA,
-                                         mnemonic: "RES 7, D",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xBB,
-                                         mnemonic: "RES 7, E",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xBC,
-                                         mnemonic: "RES 7, H",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xBD,
-                                         mnemonic: "RES 7, L",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xBE,
-                                         mnemonic: "RES 7, (HL)",
-                                         length: 1,
-                                         cycles: 16,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xBF,
-                                         mnemonic: "RES 7, A",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xC0,
-                                         mnemonic: "SET 0, B",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xC1,
-                                         mnemonic: "SET 0, C",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xC2,
-                                         mnemonic: "SET 0, D",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xC3,
-                                         mnemonic: "SET 0, E",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xC4,
-                                         mnemonic: "SET 0, H",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xC5,
-                                         mnemonic: "SET 0, L",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xC6,
-                                         mnemonic: "SET 0, (HL)",
-                                         length: 1,
-                                         cycles: 16,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xC7,
-                                         mnemonic: "SET 0, A",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xC8,
-                                         mnemonic: "SET 1, B",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xC9,
-                                         mnemonic: "SET 1, C",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xCA,
-                                         mnemonic: "SET 1, D",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xCB,
-                                         mnemonic: "SET 1, E",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xCC,
-                                         mnemonic: "SET 1, H",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xCD,
-                                         mnemonic: "SET 1, L",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xCE,
-                                         mnemonic: "SET 1, (HL)",
-                                         length: 1,
-                                         cycles: 16,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xCF,
-                                         mnemonic: "SET 1, A",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xD0,
-                                         mnemonic: "SET 2, B",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xD1,
-                                         mnemonic: "SET 2, C",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xD2,
-                                         mnemonic: "SET 2, D",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xD3,
-                                         mnemonic: "SET 2, E",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xD4,
-                                         mnemonic: "SET 2, H",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xD5,
-                                         mnemonic: "SET 2, L",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xD6,
-                                         mnemonic: "SET 2, (HL)",
-                                         length: 1,
-                                         cycles: 16,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xD7,
-                                         mnemonic: "SET 2, A",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xD8,
-                                         mnemonic: "SET 3, B",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xD9,
-                                         mnemonic: "SET 3, C",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xDA,
-                                         mnemonic: "SET 3, D",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xDB,
-                                         mnemonic: "SET 3, E",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xDC,
-                                         mnemonic: "SET 3, H",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xDD,
-                                         mnemonic: "SET 3, L",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xDE,
-                                         mnemonic: "SET 3, (HL)",
-                                         length: 1,
-                                         cycles: 16,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xDF,
-                                         mnemonic: "SET 3, A",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xE0,
-                                         mnemonic: "SET 4, B",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xE1,
-                                         mnemonic: "SET 4, C",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xE2,
-                                         mnemonic: "SET 4, D",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xE3,
-                                         mnemonic: "SET 4, E",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xE4,
-                                         mnemonic: "SET 4, H",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xE5,
-                                         mnemonic: "SET 4, L",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xE6,
-                                         mnemonic: "SET 4, (HL)",
-                                         length: 1,
-                                         cycles: 16,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xE7,
-                                         mnemonic: "SET 4, A",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xE8,
-                                         mnemonic: "SET 5, B",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xE9,
-                                         mnemonic: "SET 5, C",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xEA,
-                                         mnemonic: "SET 5, D",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xEB,
-                                         mnemonic: "SET 5, E",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xEC,
-                                         mnemonic: "SET 5, H",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xED,
-                                         mnemonic: "SET 5, L",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xEE,
-                                         mnemonic: "SET 5, (HL)",
-                                         length: 1,
-                                         cycles: 16,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xEF,
-                                         mnemonic: "SET 5, A",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xF0,
-                                         mnemonic: "SET 6, B",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xF1,
-                                         mnemonic: "SET 6, C",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xF2,
-                                         mnemonic: "SET 6, D",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xF3,
-                                         mnemonic: "SET 6, E",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xF4,
-                                         mnemonic: "SET 6, H",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xF5,
-                                         mnemonic: "SET 6, L",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xF6,
-                                         mnemonic: "SET 6, (HL)",
-                                         length: 1,
-                                         cycles: 16,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xF7,
-                                         mnemonic: "SET 6, A",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xF8,
-                                         mnemonic: "SET 7, B",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xF9,
-                                         mnemonic: "SET 7, C",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xFA,
-                                         mnemonic: "SET 7, D",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xFB,
-                                         mnemonic: "SET 7, E",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xFC,
-                                         mnemonic: "SET 7, H",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xFD,
-                                         mnemonic: "SET 7, L",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xFE,
-                                         mnemonic: "SET 7, (HL)",
-                                         length: 1,
-                                         cycles: 16,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     },
-                                     OpCode {
-                                         code: 0xFF,
-                                         mnemonic: "SET 7, A",
-                                         length: 1,
-                                         cycles: 8,
-                                         argument_type: ArgumentType::Implied,
-                                         extended: true,
-                                     }];
+    true
+}
+
+const fn ext_table_in_order(table: &[OpCode; 256]) -> bool {
+    let mut i = 0;
+    while i < 256 {
+        if table[i].code as usize != i || !table[i].extended {
+            return false;
+        }
+        i += 1;
+    }
+
+    true
+}
+
+// Indexed directly by opcode. 0xCB is the prefix for the extended table and the
+// gaps are opcodes that lock up the CPU on real hardware.
+const OPCODE_TABLE: [Option<OpCode>; 256] = [
+    Some(OpCode {
+        code: 0x00,
+        mnemonic: "NOP",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x01,
+        mnemonic: "LD BC, {imm16}",
+        length: 3,
+        cycles: 12,
+        cycles_taken: 12,
+        argument_type: ArgumentType::Imm16,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x02,
+        mnemonic: "LD (BC), A",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x03,
+        mnemonic: "INC BC",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x04,
+        mnemonic: "INC B",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x05,
+        mnemonic: "DEC B",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x06,
+        mnemonic: "LD B, {imm8}",
+        length: 2,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Imm8,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x07,
+        mnemonic: "RCLA",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x08,
+        mnemonic: "LD ({imm16}), SP",
+        length: 3,
+        cycles: 20,
+        cycles_taken: 20,
+        argument_type: ArgumentType::Imm16,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x09,
+        mnemonic: "ADD HL, BC",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x0A,
+        mnemonic: "LD A, (BC)",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x0B,
+        mnemonic: "DEC BC",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x0C,
+        mnemonic: "INC C",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x0D,
+        mnemonic: "DEC C",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x0E,
+        mnemonic: "LD C, {imm8}",
+        length: 2,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Imm8,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x0F,
+        mnemonic: "RRCA",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x10,
+        mnemonic: "STOP",
+        length: 2, // theres a $00 after this opcode
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x11,
+        mnemonic: "LD DE, {imm16}",
+        length: 3,
+        cycles: 12,
+        cycles_taken: 12,
+        argument_type: ArgumentType::Imm16,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x12,
+        mnemonic: "LD (DE), A",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x13,
+        mnemonic: "INC DE",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x14,
+        mnemonic: "INC D",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x15,
+        mnemonic: "DEC D",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x16,
+        mnemonic: "LD D, {imm8}",
+        length: 2,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Imm8,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x17,
+        mnemonic: "RLA",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x18,
+        mnemonic: "JP {imm8}",
+        length: 2,
+        cycles: 12,
+        cycles_taken: 12,
+        argument_type: ArgumentType::Imm8,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x19,
+        mnemonic: "ADD HL, DE",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x1A,
+        mnemonic: "LD A, (DE)",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x1B,
+        mnemonic: "DEC DE",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x1C,
+        mnemonic: "INC E",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x1D,
+        mnemonic: "DEC E",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x1E,
+        mnemonic: "LD E, {imm8}",
+        length: 2,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Imm8,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x1F,
+        mnemonic: "RRA",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x20,
+        mnemonic: "JR NZ, {imm8}",
+        length: 2,
+        cycles: 8,
+        cycles_taken: 12,
+        argument_type: ArgumentType::Imm8,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x21,
+        mnemonic: "LD HL, {imm16}",
+        length: 3,
+        cycles: 12,
+        cycles_taken: 12,
+        argument_type: ArgumentType::Imm16,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x22,
+        mnemonic: "LD (HLI), A",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x23,
+        mnemonic: "INC HL",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x24,
+        mnemonic: "INC H",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x25,
+        mnemonic: "DEC H",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x26,
+        mnemonic: "LD H, {imm8}",
+        length: 2,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Imm8,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x27,
+        mnemonic: "DAA",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x28,
+        mnemonic: "JR Z, {imm8}",
+        length: 2,
+        cycles: 8,
+        cycles_taken: 12,
+        argument_type: ArgumentType::Imm8,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x29,
+        mnemonic: "ADD HL, HL",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x2A,
+        mnemonic: "LD A, (HLI)",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x2B,
+        mnemonic: "DEC HL",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x2C,
+        mnemonic: "INC L",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x2D,
+        mnemonic: "DEC L",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x2E,
+        mnemonic: "LD L, {imm8}",
+        length: 2,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Imm8,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x2F,
+        mnemonic: "CPL",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x30,
+        mnemonic: "JR NC, {imm8}",
+        length: 2,
+        cycles: 8,
+        cycles_taken: 12,
+        argument_type: ArgumentType::Imm8,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x31,
+        mnemonic: "LD SP, {imm16}",
+        length: 3,
+        cycles: 12,
+        cycles_taken: 12,
+        argument_type: ArgumentType::Imm16,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x32,
+        mnemonic: "LD (HLD), A",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x33,
+        mnemonic: "INC SP",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x34,
+        mnemonic: "INC (HL)",
+        length: 1,
+        cycles: 12,
+        cycles_taken: 12,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x35,
+        mnemonic: "DEC (HL)",
+        length: 1,
+        cycles: 12,
+        cycles_taken: 12,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x36,
+        mnemonic: "LD (HL), {imm8}",
+        length: 2,
+        cycles: 12,
+        cycles_taken: 12,
+        argument_type: ArgumentType::Imm8,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x37,
+        mnemonic: "SCF",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x38,
+        mnemonic: "JR C, {imm8}",
+        length: 2,
+        cycles: 8,
+        cycles_taken: 12,
+        argument_type: ArgumentType::Imm8,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x39,
+        mnemonic: "ADD HL, SP",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x3A,
+        mnemonic: "LD A, (HLD)",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x3B,
+        mnemonic: "DEC SP",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x3C,
+        mnemonic: "INC A",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x3D,
+        mnemonic: "DEC A",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x3E,
+        mnemonic: "LD A, {imm8}",
+        length: 2,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Imm8,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x3F,
+        mnemonic: "CCF",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x40,
+        mnemonic: "LD B, B",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x41,
+        mnemonic: "LD B, C",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x42,
+        mnemonic: "LD B, D",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x43,
+        mnemonic: "LD B, E",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x44,
+        mnemonic: "LD B, H",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x45,
+        mnemonic: "LD B, L",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x46,
+        mnemonic: "LD B, (HL)",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x47,
+        mnemonic: "LD B, A",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x48,
+        mnemonic: "LD C, B",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x49,
+        mnemonic: "LD C, C",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x4A,
+        mnemonic: "LD C, D",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x4B,
+        mnemonic: "LD C, E",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x4C,
+        mnemonic: "LD C, H",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x4D,
+        mnemonic: "LD C, L",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x4E,
+        mnemonic: "LD C, (HL)",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x4F,
+        mnemonic: "LD C, A",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x50,
+        mnemonic: "LD D, B",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x51,
+        mnemonic: "LD D, C",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x52,
+        mnemonic: "LD D, D",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x53,
+        mnemonic: "LD D, E",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x54,
+        mnemonic: "LD D, H",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x55,
+        mnemonic: "LD D, L",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x56,
+        mnemonic: "LD D, (HL)",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x57,
+        mnemonic: "LD D, A",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x58,
+        mnemonic: "LD E, B",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x59,
+        mnemonic: "LD E, C",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x5A,
+        mnemonic: "LD E, D",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x5B,
+        mnemonic: "LD E, E",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x5C,
+        mnemonic: "LD E, H",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x5D,
+        mnemonic: "LD E, L",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x5E,
+        mnemonic: "LD E, (HL)",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x5F,
+        mnemonic: "LD E, A",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x60,
+        mnemonic: "LD H, B",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x61,
+        mnemonic: "LD H, C",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x62,
+        mnemonic: "LD H, D",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x63,
+        mnemonic: "LD H, E",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x64,
+        mnemonic: "LD H, H",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x65,
+        mnemonic: "LD H, L",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x66,
+        mnemonic: "LD H, (HL)",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x67,
+        mnemonic: "LD H, A",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x68,
+        mnemonic: "LD L, B",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x69,
+        mnemonic: "LD L, C",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x6A,
+        mnemonic: "LD L, D",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x6B,
+        mnemonic: "LD L, E",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x6C,
+        mnemonic: "LD L, H",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x6D,
+        mnemonic: "LD L, L",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x6E,
+        mnemonic: "LD L, (HL)",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x6F,
+        mnemonic: "LD L, A",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x70,
+        mnemonic: "LD (HL), B",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x71,
+        mnemonic: "LD (HL), C",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x72,
+        mnemonic: "LD (HL), D",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x73,
+        mnemonic: "LD (HL), E",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x74,
+        mnemonic: "LD (HL), H",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x75,
+        mnemonic: "LD (HL), L",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x76,
+        mnemonic: "HALT",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x77,
+        mnemonic: "LD (HL), A",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x78,
+        mnemonic: "LD A, B",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x79,
+        mnemonic: "LD A, C",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x7A,
+        mnemonic: "LD A, D",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x7B,
+        mnemonic: "LD A, E",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x7C,
+        mnemonic: "LD A, H",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x7D,
+        mnemonic: "LD A, L",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x7E,
+        mnemonic: "LD A, (HL)",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x7F,
+        mnemonic: "LD A, A",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x80,
+        mnemonic: "ADD A, B",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x81,
+        mnemonic: "ADD A, C",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x82,
+        mnemonic: "ADD A, D",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x83,
+        mnemonic: "ADD A, E",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x84,
+        mnemonic: "ADD A, H",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x85,
+        mnemonic: "ADD A, L",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x86,
+        mnemonic: "ADD A, (HL)",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x87,
+        mnemonic: "AND A, A",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x88,
+        mnemonic: "ADC A, B",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x89,
+        mnemonic: "ADC A, C",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x8A,
+        mnemonic: "ADC A, D",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x8B,
+        mnemonic: "ADC A, E",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x8C,
+        mnemonic: "ADC A, H",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x8D,
+        mnemonic: "ADC A, L",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x8E,
+        mnemonic: "ADC A, (HL)",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x8F,
+        mnemonic: "ADC A, A",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x90,
+        mnemonic: "SUB B",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x91,
+        mnemonic: "SUB C",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x92,
+        mnemonic: "SUB D",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x93,
+        mnemonic: "SUB E",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x94,
+        mnemonic: "SUB H",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x95,
+        mnemonic: "SUB L",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x96,
+        mnemonic: "SUB (HL)",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x97,
+        mnemonic: "SUB A",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x98,
+        mnemonic: "SBC A, B",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x99,
+        mnemonic: "SBC A, C",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x9A,
+        mnemonic: "SBC A, D",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x9B,
+        mnemonic: "SBC A, E",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x9C,
+        mnemonic: "SBC A, H",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x9D,
+        mnemonic: "SBC A, L",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x9E,
+        mnemonic: "SBC A, (HL)",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0x9F,
+        mnemonic: "SBC A, A",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xA0,
+        mnemonic: "AND B",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xA1,
+        mnemonic: "AND C",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xA2,
+        mnemonic: "AND D",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xA3,
+        mnemonic: "AND E",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xA4,
+        mnemonic: "AND H",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xA5,
+        mnemonic: "AND L",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xA6,
+        mnemonic: "AND (HL)",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xA7,
+        mnemonic: "AND A",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xA8,
+        mnemonic: "XOR B",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xA9,
+        mnemonic: "XOR C",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xAA,
+        mnemonic: "XOR D",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xAB,
+        mnemonic: "XOR E",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xAC,
+        mnemonic: "XOR H",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xAD,
+        mnemonic: "XOR L",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xAE,
+        mnemonic: "XOR (HL)",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xAF,
+        mnemonic: "XOR A",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xB0,
+        mnemonic: "OR B",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xB1,
+        mnemonic: "OR C",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xB2,
+        mnemonic: "OR D",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xB3,
+        mnemonic: "OR E",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xB4,
+        mnemonic: "OR H",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xB5,
+        mnemonic: "OR L",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xB6,
+        mnemonic: "OR (HL)",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xB7,
+        mnemonic: "OR A",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xB8,
+        mnemonic: "CP B",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xB9,
+        mnemonic: "CP C",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xBA,
+        mnemonic: "CP D",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xBB,
+        mnemonic: "CP E",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xBC,
+        mnemonic: "CP H",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xBD,
+        mnemonic: "CP L",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xBE,
+        mnemonic: "CP (HL)",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xBF,
+        mnemonic: "CP A",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xC0,
+        mnemonic: "RET NZ",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 20,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xC1,
+        mnemonic: "POP BC",
+        length: 1,
+        cycles: 12,
+        cycles_taken: 12,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xC2,
+        mnemonic: "JP NZ, {imm16}",
+        length: 3,
+        cycles: 12,
+        cycles_taken: 16,
+        argument_type: ArgumentType::Imm16,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xC3,
+        mnemonic: "JP {imm16}",
+        length: 3,
+        cycles: 16,
+        cycles_taken: 16,
+        argument_type: ArgumentType::Imm16,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xC4,
+        mnemonic: "CALL NZ, {imm16}",
+        length: 3,
+        cycles: 12,
+        cycles_taken: 24,
+        argument_type: ArgumentType::Imm16,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xC5,
+        mnemonic: "PUSH BC",
+        length: 1,
+        cycles: 16,
+        cycles_taken: 16,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xC6,
+        mnemonic: "ADD A, {imm8}",
+        length: 2,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Imm8,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xC7,
+        mnemonic: "RST 0x00",
+        length: 1,
+        cycles: 16,
+        cycles_taken: 16,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xC8,
+        mnemonic: "RET Z",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 20,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xC9,
+        mnemonic: "RET",
+        length: 1,
+        cycles: 16,
+        cycles_taken: 16,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xCA,
+        mnemonic: "JP Z, {imm16}",
+        length: 3,
+        cycles: 12,
+        cycles_taken: 16,
+        argument_type: ArgumentType::Imm16,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xCB,
+        mnemonic: "",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xCC,
+        mnemonic: "CALL Z, {imm16}",
+        length: 3,
+        cycles: 12,
+        cycles_taken: 24,
+        argument_type: ArgumentType::Imm16,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xCD,
+        mnemonic: "CALL {imm16}",
+        length: 3,
+        cycles: 24,
+        cycles_taken: 24,
+        argument_type: ArgumentType::Imm16,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xCE,
+        mnemonic: "ADC A, {imm8}",
+        length: 2,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Imm8,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xCF,
+        mnemonic: "RST 0x08",
+        length: 1,
+        cycles: 16,
+        cycles_taken: 16,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xD0,
+        mnemonic: "RET NC",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 20,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xD1,
+        mnemonic: "POP DE",
+        length: 1,
+        cycles: 12,
+        cycles_taken: 12,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xD2,
+        mnemonic: "JP NC, {imm16}",
+        length: 3,
+        cycles: 12,
+        cycles_taken: 16,
+        argument_type: ArgumentType::Imm16,
+        extended: false,
+    }),
+    None, // 0xD3
+    Some(OpCode {
+        code: 0xD4,
+        mnemonic: "CALL NC, {imm16}",
+        length: 3,
+        cycles: 12,
+        cycles_taken: 24,
+        argument_type: ArgumentType::Imm16,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xD5,
+        mnemonic: "PUSH DE",
+        length: 1,
+        cycles: 16,
+        cycles_taken: 16,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xD6,
+        mnemonic: "SUB {imm8}",
+        length: 2,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Imm8,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xD7,
+        mnemonic: "RST 0x10",
+        length: 1,
+        cycles: 16,
+        cycles_taken: 16,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xD8,
+        mnemonic: "RET C",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 20,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xD9,
+        mnemonic: "RETI",
+        length: 1,
+        cycles: 16,
+        cycles_taken: 16,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xDA,
+        mnemonic: "JP C, {imm16}",
+        length: 3,
+        cycles: 12,
+        cycles_taken: 16,
+        argument_type: ArgumentType::Imm16,
+        extended: false,
+    }),
+    None, // 0xDB
+    Some(OpCode {
+        code: 0xDC,
+        mnemonic: "CALL C, {imm16}",
+        length: 3,
+        cycles: 12,
+        cycles_taken: 24,
+        argument_type: ArgumentType::Imm16,
+        extended: false,
+    }),
+    None, // 0xDD
+    Some(OpCode {
+        code: 0xDE,
+        mnemonic: "SBC A, {imm8}",
+        length: 2,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Imm8,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xDF,
+        mnemonic: "RST 0x18",
+        length: 1,
+        cycles: 16,
+        cycles_taken: 16,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xE0,
+        mnemonic: "LD ($FF00+{imm8}), A",
+        length: 2,
+        cycles: 12,
+        cycles_taken: 12,
+        argument_type: ArgumentType::Imm8,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xE1,
+        mnemonic: "POP HL",
+        length: 1,
+        cycles: 12,
+        cycles_taken: 12,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xE2,
+        mnemonic: "LD ($FF00+C), A",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    None, // 0xE3
+    None, // 0xE4
+    Some(OpCode {
+        code: 0xE5,
+        mnemonic: "PUSH HL",
+        length: 1,
+        cycles: 16,
+        cycles_taken: 16,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xE6,
+        mnemonic: "AND {imm8}",
+        length: 2,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Imm8,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xE7,
+        mnemonic: "RST 0x20",
+        length: 1,
+        cycles: 16,
+        cycles_taken: 16,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xE8,
+        mnemonic: "ADD SP, {imm8}",
+        length: 2,
+        cycles: 16,
+        cycles_taken: 16,
+        argument_type: ArgumentType::Imm8,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xE9,
+        mnemonic: "JP (HL)",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xEA,
+        mnemonic: "LD ({imm16}), A",
+        length: 3,
+        cycles: 16,
+        cycles_taken: 16,
+        argument_type: ArgumentType::Imm16,
+        extended: false,
+    }),
+    None, // 0xEB
+    None, // 0xEC
+    None, // 0xED
+    Some(OpCode {
+        code: 0xEE,
+        mnemonic: "XOR A, {imm8}",
+        length: 2,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Imm8,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xEF,
+        mnemonic: "RST 0x28",
+        length: 1,
+        cycles: 16,
+        cycles_taken: 16,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xF0,
+        mnemonic: "LD A, ($FF00+{imm8})",
+        length: 2,
+        cycles: 12,
+        cycles_taken: 12,
+        argument_type: ArgumentType::Imm8,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xF1,
+        mnemonic: "POP AF",
+        length: 1,
+        cycles: 12,
+        cycles_taken: 12,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xF2,
+        mnemonic: "LD A, (C)",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xF3,
+        mnemonic: "DI",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    None, // 0xF4
+    Some(OpCode {
+        code: 0xF5,
+        mnemonic: "PUSH AF",
+        length: 1,
+        cycles: 16,
+        cycles_taken: 16,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xF6,
+        mnemonic: "OR {imm8}",
+        length: 2,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Imm8,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xF7,
+        mnemonic: "RST 0x30",
+        length: 1,
+        cycles: 16,
+        cycles_taken: 16,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xF8,
+        mnemonic: "LDHL SP, {imm8}",
+        length: 2,
+        cycles: 12,
+        cycles_taken: 12,
+        argument_type: ArgumentType::Imm8,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xF9,
+        mnemonic: "LD SP, HL",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xFA,
+        mnemonic: "LD A, ({imm16})",
+        length: 3,
+        cycles: 16,
+        cycles_taken: 16,
+        argument_type: ArgumentType::Imm16,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xFB,
+        mnemonic: "EI",
+        length: 1,
+        cycles: 4,
+        cycles_taken: 4,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+    None, // 0xFC
+    None, // 0xFD
+    Some(OpCode {
+        code: 0xFE,
+        mnemonic: "CP {imm8}",
+        length: 2,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Imm8,
+        extended: false,
+    }),
+    Some(OpCode {
+        code: 0xFF,
+        mnemonic: "RST 0x38",
+        length: 1,
+        cycles: 16,
+        cycles_taken: 16,
+        argument_type: ArgumentType::Implied,
+        extended: false,
+    }),
+];
+
+// EXTENDED OPCODES (all prefixed with 0xCB)
+const EXT_OPCODE_TABLE: [OpCode; 256] = [
+    OpCode {
+        code: 0x00,
+        mnemonic: "RLC B",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x01,
+        mnemonic: "RLC C",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x02,
+        mnemonic: "RLC D",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x03,
+        mnemonic: "RLC E",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x04,
+        mnemonic: "RLC H",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x05,
+        mnemonic: "RLC L",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x06,
+        mnemonic: "RLC (HL)",
+        length: 1,
+        cycles: 16,
+        cycles_taken: 16,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x07,
+        mnemonic: "RLC A",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x08,
+        mnemonic: "RRC B",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x09,
+        mnemonic: "RRC C",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x0A,
+        mnemonic: "RRC D",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x0B,
+        mnemonic: "RRC E",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x0C,
+        mnemonic: "RRC H",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x0D,
+        mnemonic: "RRC L",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x0E,
+        mnemonic: "RRC (HL)",
+        length: 1,
+        cycles: 16,
+        cycles_taken: 16,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x0F,
+        mnemonic: "RRC A",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x10,
+        mnemonic: "RL B",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x11,
+        mnemonic: "RL C",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x12,
+        mnemonic: "RL D",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x13,
+        mnemonic: "RL E",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x14,
+        mnemonic: "RL H",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x15,
+        mnemonic: "RL L",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x16,
+        mnemonic: "RL (HL)",
+        length: 1,
+        cycles: 16,
+        cycles_taken: 16,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x17,
+        mnemonic: "RL A",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x18,
+        mnemonic: "RR B",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x19,
+        mnemonic: "RR C",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x1A,
+        mnemonic: "RR D",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x1B,
+        mnemonic: "RR E",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x1C,
+        mnemonic: "RR H",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x1D,
+        mnemonic: "RR L",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x1E,
+        mnemonic: "RR (HL)",
+        length: 1,
+        cycles: 16,
+        cycles_taken: 16,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x1F,
+        mnemonic: "RR A",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x20,
+        mnemonic: "SLA B",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x21,
+        mnemonic: "SLA C",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x22,
+        mnemonic: "SLA D",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x23,
+        mnemonic: "SLA E",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x24,
+        mnemonic: "SLA H",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x25,
+        mnemonic: "SLA L",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x26,
+        mnemonic: "SLA (HL)",
+        length: 1,
+        cycles: 16,
+        cycles_taken: 16,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x27,
+        mnemonic: "SLA A",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x28,
+        mnemonic: "SRA B",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x29,
+        mnemonic: "SRA C",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x2A,
+        mnemonic: "SRA D",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x2B,
+        mnemonic: "SRA E",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x2C,
+        mnemonic: "SRA H",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x2D,
+        mnemonic: "SRA L",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x2E,
+        mnemonic: "SRA (HL)",
+        length: 1,
+        cycles: 16,
+        cycles_taken: 16,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x2F,
+        mnemonic: "SRA A",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x30,
+        mnemonic: "SWAP B",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x31,
+        mnemonic: "SWAP C",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x32,
+        mnemonic: "SWAP D",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x33,
+        mnemonic: "SWAP E",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x34,
+        mnemonic: "SWAP H",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x35,
+        mnemonic: "SWAP L",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x36,
+        mnemonic: "SWAP (HL)",
+        length: 1,
+        cycles: 16,
+        cycles_taken: 16,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x37,
+        mnemonic: "SWAP A",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x38,
+        mnemonic: "SRL B",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x39,
+        mnemonic: "SRL C",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x3A,
+        mnemonic: "SRL D",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x3B,
+        mnemonic: "SRL E",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x3C,
+        mnemonic: "SRL H",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x3D,
+        mnemonic: "SRL L",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x3E,
+        mnemonic: "SRL (HL)",
+        length: 1,
+        cycles: 16,
+        cycles_taken: 16,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x3F,
+        mnemonic: "SRL A",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x40,
+        mnemonic: "BIT 0, B",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x41,
+        mnemonic: "BIT 0, C",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x42,
+        mnemonic: "BIT 0, D",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x43,
+        mnemonic: "BIT 0, E",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x44,
+        mnemonic: "BIT 0, H",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x45,
+        mnemonic: "BIT 0, L",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x46,
+        mnemonic: "BIT 0, (HL)",
+        length: 1,
+        cycles: 12,
+        cycles_taken: 12,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x47,
+        mnemonic: "BIT 0, A",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x48,
+        mnemonic: "BIT 1, B",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x49,
+        mnemonic: "BIT 1, C",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x4A,
+        mnemonic: "BIT 1, D",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x4B,
+        mnemonic: "BIT 1, E",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x4C,
+        mnemonic: "BIT 1, H",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x4D,
+        mnemonic: "BIT 1, L",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x4E,
+        mnemonic: "BIT 1, (HL)",
+        length: 1,
+        cycles: 12,
+        cycles_taken: 12,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x4F,
+        mnemonic: "BIT 1, A",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x50,
+        mnemonic: "BIT 2, B",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x51,
+        mnemonic: "BIT 2, C",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x52,
+        mnemonic: "BIT 2, D",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x53,
+        mnemonic: "BIT 2, E",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x54,
+        mnemonic: "BIT 2, H",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x55,
+        mnemonic: "BIT 2, L",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x56,
+        mnemonic: "BIT 2, (HL)",
+        length: 1,
+        cycles: 12,
+        cycles_taken: 12,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x57,
+        mnemonic: "BIT 2, A",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x58,
+        mnemonic: "BIT 3, B",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x59,
+        mnemonic: "BIT 3, C",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x5A,
+        mnemonic: "BIT 3, D",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x5B,
+        mnemonic: "BIT 3, E",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x5C,
+        mnemonic: "BIT 3, H",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x5D,
+        mnemonic: "BIT 3, L",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x5E,
+        mnemonic: "BIT 3, (HL)",
+        length: 1,
+        cycles: 12,
+        cycles_taken: 12,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x5F,
+        mnemonic: "BIT 3, A",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x60,
+        mnemonic: "BIT 4, B",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x61,
+        mnemonic: "BIT 4, C",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x62,
+        mnemonic: "BIT 4, D",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x63,
+        mnemonic: "BIT 4, E",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x64,
+        mnemonic: "BIT 4, H",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x65,
+        mnemonic: "BIT 4, L",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x66,
+        mnemonic: "BIT 4, (HL)",
+        length: 1,
+        cycles: 12,
+        cycles_taken: 12,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x67,
+        mnemonic: "BIT 4, A",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x68,
+        mnemonic: "BIT 5, B",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x69,
+        mnemonic: "BIT 5, C",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x6A,
+        mnemonic: "BIT 5, D",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x6B,
+        mnemonic: "BIT 5, E",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x6C,
+        mnemonic: "BIT 5, H",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x6D,
+        mnemonic: "BIT 5, L",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x6E,
+        mnemonic: "BIT 5, (HL)",
+        length: 1,
+        cycles: 12,
+        cycles_taken: 12,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x6F,
+        mnemonic: "BIT 5, A",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x70,
+        mnemonic: "BIT 6, B",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x71,
+        mnemonic: "BIT 6, C",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x72,
+        mnemonic: "BIT 6, D",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x73,
+        mnemonic: "BIT 6, E",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x74,
+        mnemonic: "BIT 6, H",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x75,
+        mnemonic: "BIT 6, L",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x76,
+        mnemonic: "BIT 6, (HL)",
+        length: 1,
+        cycles: 12,
+        cycles_taken: 12,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x77,
+        mnemonic: "BIT 6, A",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x78,
+        mnemonic: "BIT 7, B",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x79,
+        mnemonic: "BIT 7, C",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x7A,
+        mnemonic: "BIT 7, D",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x7B,
+        mnemonic: "BIT 7, E",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x7C,
+        mnemonic: "BIT 7, H",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x7D,
+        mnemonic: "BIT 7, L",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x7E,
+        mnemonic: "BIT 7, (HL)",
+        length: 1,
+        cycles: 12,
+        cycles_taken: 12,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x7F,
+        mnemonic: "BIT 7, A",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x80,
+        mnemonic: "RES 0, B",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x81,
+        mnemonic: "RES 0, C",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x82,
+        mnemonic: "RES 0, D",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x83,
+        mnemonic: "RES 0, E",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x84,
+        mnemonic: "RES 0, H",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x85,
+        mnemonic: "RES 0, L",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x86,
+        mnemonic: "RES 0, (HL)",
+        length: 1,
+        cycles: 16,
+        cycles_taken: 16,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x87,
+        mnemonic: "RES 0, A",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x88,
+        mnemonic: "RES 1, B",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x89,
+        mnemonic: "RES 1, C",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x8A,
+        mnemonic: "RES 1, D",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x8B,
+        mnemonic: "RES 1, E",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x8C,
+        mnemonic: "RES 1, H",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x8D,
+        mnemonic: "RES 1, L",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x8E,
+        mnemonic: "RES 1, (HL)",
+        length: 1,
+        cycles: 16,
+        cycles_taken: 16,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x8F,
+        mnemonic: "RES 1, A",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x90,
+        mnemonic: "RES 2, B",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x91,
+        mnemonic: "RES 2, C",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x92,
+        mnemonic: "RES 2, D",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x93,
+        mnemonic: "RES 2, E",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x94,
+        mnemonic: "RES 2, H",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x95,
+        mnemonic: "RES 2, L",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x96,
+        mnemonic: "RES 2, (HL)",
+        length: 1,
+        cycles: 16,
+        cycles_taken: 16,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x97,
+        mnemonic: "RES 2, A",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x98,
+        mnemonic: "RES 3, B",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x99,
+        mnemonic: "RES 3, C",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x9A,
+        mnemonic: "RES 3, D",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x9B,
+        mnemonic: "RES 3, E",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x9C,
+        mnemonic: "RES 3, H",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x9D,
+        mnemonic: "RES 3, L",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x9E,
+        mnemonic: "RES 3, (HL)",
+        length: 1,
+        cycles: 16,
+        cycles_taken: 16,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0x9F,
+        mnemonic: "RES 3, A",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xA0,
+        mnemonic: "RES 4, B",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xA1,
+        mnemonic: "RES 4, C",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xA2,
+        mnemonic: "RES 4, D",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xA3,
+        mnemonic: "RES 4, E",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xA4,
+        mnemonic: "RES 4, H",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xA5,
+        mnemonic: "RES 4, L",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xA6,
+        mnemonic: "RES 4, (HL)",
+        length: 1,
+        cycles: 16,
+        cycles_taken: 16,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xA7,
+        mnemonic: "RES 4, A",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xA8,
+        mnemonic: "RES 5, B",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xA9,
+        mnemonic: "RES 5, C",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xAA,
+        mnemonic: "RES 5, D",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xAB,
+        mnemonic: "RES 5, E",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xAC,
+        mnemonic: "RES 5, H",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xAD,
+        mnemonic: "RES 5, L",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xAE,
+        mnemonic: "RES 5, (HL)",
+        length: 1,
+        cycles: 16,
+        cycles_taken: 16,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xAF,
+        mnemonic: "RES 5, A",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xB0,
+        mnemonic: "RES 6, B",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xB1,
+        mnemonic: "RES 6, C",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xB2,
+        mnemonic: "RES 6, D",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xB3,
+        mnemonic: "RES 6, E",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xB4,
+        mnemonic: "RES 6, H",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xB5,
+        mnemonic: "RES 6, L",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xB6,
+        mnemonic: "RES 6, (HL)",
+        length: 1,
+        cycles: 16,
+        cycles_taken: 16,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xB7,
+        mnemonic: "RES 6, A",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xB8,
+        mnemonic: "RES 7, B",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xB9,
+        mnemonic: "RES 7, C",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xBA,
+        mnemonic: "RES 7, D",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xBB,
+        mnemonic: "RES 7, E",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xBC,
+        mnemonic: "RES 7, H",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xBD,
+        mnemonic: "RES 7, L",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xBE,
+        mnemonic: "RES 7, (HL)",
+        length: 1,
+        cycles: 16,
+        cycles_taken: 16,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xBF,
+        mnemonic: "RES 7, A",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xC0,
+        mnemonic: "SET 0, B",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xC1,
+        mnemonic: "SET 0, C",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xC2,
+        mnemonic: "SET 0, D",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xC3,
+        mnemonic: "SET 0, E",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xC4,
+        mnemonic: "SET 0, H",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xC5,
+        mnemonic: "SET 0, L",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xC6,
+        mnemonic: "SET 0, (HL)",
+        length: 1,
+        cycles: 16,
+        cycles_taken: 16,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xC7,
+        mnemonic: "SET 0, A",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xC8,
+        mnemonic: "SET 1, B",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xC9,
+        mnemonic: "SET 1, C",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xCA,
+        mnemonic: "SET 1, D",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xCB,
+        mnemonic: "SET 1, E",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xCC,
+        mnemonic: "SET 1, H",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xCD,
+        mnemonic: "SET 1, L",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xCE,
+        mnemonic: "SET 1, (HL)",
+        length: 1,
+        cycles: 16,
+        cycles_taken: 16,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xCF,
+        mnemonic: "SET 1, A",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xD0,
+        mnemonic: "SET 2, B",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xD1,
+        mnemonic: "SET 2, C",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xD2,
+        mnemonic: "SET 2, D",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xD3,
+        mnemonic: "SET 2, E",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xD4,
+        mnemonic: "SET 2, H",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xD5,
+        mnemonic: "SET 2, L",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xD6,
+        mnemonic: "SET 2, (HL)",
+        length: 1,
+        cycles: 16,
+        cycles_taken: 16,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xD7,
+        mnemonic: "SET 2, A",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xD8,
+        mnemonic: "SET 3, B",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xD9,
+        mnemonic: "SET 3, C",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xDA,
+        mnemonic: "SET 3, D",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xDB,
+        mnemonic: "SET 3, E",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xDC,
+        mnemonic: "SET 3, H",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xDD,
+        mnemonic: "SET 3, L",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xDE,
+        mnemonic: "SET 3, (HL)",
+        length: 1,
+        cycles: 16,
+        cycles_taken: 16,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xDF,
+        mnemonic: "SET 3, A",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xE0,
+        mnemonic: "SET 4, B",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xE1,
+        mnemonic: "SET 4, C",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xE2,
+        mnemonic: "SET 4, D",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xE3,
+        mnemonic: "SET 4, E",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xE4,
+        mnemonic: "SET 4, H",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xE5,
+        mnemonic: "SET 4, L",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xE6,
+        mnemonic: "SET 4, (HL)",
+        length: 1,
+        cycles: 16,
+        cycles_taken: 16,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xE7,
+        mnemonic: "SET 4, A",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xE8,
+        mnemonic: "SET 5, B",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xE9,
+        mnemonic: "SET 5, C",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xEA,
+        mnemonic: "SET 5, D",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xEB,
+        mnemonic: "SET 5, E",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xEC,
+        mnemonic: "SET 5, H",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xED,
+        mnemonic: "SET 5, L",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xEE,
+        mnemonic: "SET 5, (HL)",
+        length: 1,
+        cycles: 16,
+        cycles_taken: 16,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xEF,
+        mnemonic: "SET 5, A",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xF0,
+        mnemonic: "SET 6, B",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xF1,
+        mnemonic: "SET 6, C",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xF2,
+        mnemonic: "SET 6, D",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xF3,
+        mnemonic: "SET 6, E",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xF4,
+        mnemonic: "SET 6, H",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xF5,
+        mnemonic: "SET 6, L",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xF6,
+        mnemonic: "SET 6, (HL)",
+        length: 1,
+        cycles: 16,
+        cycles_taken: 16,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xF7,
+        mnemonic: "SET 6, A",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xF8,
+        mnemonic: "SET 7, B",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xF9,
+        mnemonic: "SET 7, C",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xFA,
+        mnemonic: "SET 7, D",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xFB,
+        mnemonic: "SET 7, E",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xFC,
+        mnemonic: "SET 7, H",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xFD,
+        mnemonic: "SET 7, L",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xFE,
+        mnemonic: "SET 7, (HL)",
+        length: 1,
+        cycles: 16,
+        cycles_taken: 16,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+    OpCode {
+        code: 0xFF,
+        mnemonic: "SET 7, A",
+        length: 1,
+        cycles: 8,
+        cycles_taken: 8,
+        argument_type: ArgumentType::Implied,
+        extended: true,
+    },
+];
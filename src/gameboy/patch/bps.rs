@@ -0,0 +1,97 @@
+// BPS - copy/read commands against the source and the partially built target
+
+use gameboy::patch::PatchError;
+use gameboy::patch::reader::{self, PatchReader};
+
+const SOURCE_READ: usize = 0x00;
+const TARGET_READ: usize = 0x01;
+const SOURCE_COPY: usize = 0x02;
+const TARGET_COPY: usize = 0x03;
+
+pub fn apply(rom: &[u8], patch: &[u8]) -> Result<Vec<u8>, PatchError> {
+    let footer = reader::read_footer(patch)?;
+    reader::verify_source(&footer, rom)?;
+
+    let mut reader = PatchReader::new(patch, 0x04);
+    let _source_size = reader.read_varint()?;
+    let target_size = reader.read_target_size()?;
+    let metadata_size = reader.read_varint()?;
+    reader.read_bytes(metadata_size)?;
+
+    let mut output = Vec::with_capacity(target_size);
+    let mut source_offset = 0isize;
+    let mut target_offset = 0isize;
+
+    let end = patch.len() - 0x0C;
+    while reader.pos < end {
+        let data = reader.read_varint()?;
+        let command = data & 0x03;
+        let length = (data >> 0x02) + 0x01;
+
+        // Nothing may write past the declared size, so a corrupt length can't grow the output
+        if length > target_size - output.len() {
+            return Err(PatchError::InvalidCommand(format!(
+                "command writes {} bytes past the end of the target",
+                length - (target_size - output.len())
+            )));
+        }
+
+        match command {
+            SOURCE_READ => {
+                for _ in 0..length {
+                    let b = byte_at(rom, output.len() as isize)?;
+                    output.push(b);
+                }
+            }
+            TARGET_READ => output.extend_from_slice(reader.read_bytes(length)?),
+            SOURCE_COPY => {
+                source_offset = seek(source_offset, &mut reader)?;
+                for _ in 0..length {
+                    let b = byte_at(rom, source_offset)?;
+                    output.push(b);
+                    source_offset += 0x01;
+                }
+            }
+            TARGET_COPY => {
+                // The copy can overlap the bytes it is producing, so go one byte at a time
+                target_offset = seek(target_offset, &mut reader)?;
+                for _ in 0..length {
+                    let b = byte_at(&output, target_offset)?;
+                    output.push(b);
+                    target_offset += 0x01;
+                }
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    if output.len() != target_size {
+        return Err(PatchError::InvalidCommand(format!(
+            "patch produced {} bytes but should have produced {}",
+            output.len(),
+            target_size
+        )));
+    }
+
+    reader::verify_target(&footer, &output)?;
+
+    Ok(output)
+}
+
+// Moves a copy offset by the signed varint that follows the command
+fn seek(offset: isize, reader: &mut PatchReader) -> Result<isize, PatchError> {
+    let data = reader.read_varint()?;
+    let magnitude = (data >> 0x01) as isize;
+    let delta = if data & 0x01 == 0x01 { -magnitude } else { magnitude };
+
+    offset.checked_add(delta)
+        .ok_or_else(|| PatchError::InvalidCommand("copy offset moved out of range".into()))
+}
+
+fn byte_at(data: &[u8], offset: isize) -> Result<u8, PatchError> {
+    if offset < 0 || offset as usize >= data.len() {
+        return Err(PatchError::InvalidCommand(format!("copy from out of range offset {}", offset)));
+    }
+
+    Ok(data[offset as usize])
+}
@@ -0,0 +1,46 @@
+use std::error::Error;
+use std::fmt;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PatchError {
+    UnknownFormat,
+    UnexpectedEof,
+    SourceChecksumMismatch { expected: u32, actual: u32 },
+    TargetChecksumMismatch { expected: u32, actual: u32 },
+    PatchChecksumMismatch { expected: u32, actual: u32 },
+    InvalidCommand(String),
+    TargetTooLarge(usize),
+}
+
+impl fmt::Display for PatchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            PatchError::UnknownFormat => write!(f, "not an IPS, UPS or BPS patch"),
+            PatchError::UnexpectedEof => write!(f, "patch ended unexpectedly"),
+            PatchError::SourceChecksumMismatch { expected, actual } => {
+                write!(f,
+                       "patch expects a ROM with CRC32 {:08X} but this ROM is {:08X}",
+                       expected,
+                       actual)
+            }
+            PatchError::TargetChecksumMismatch { expected, actual } => {
+                write!(f,
+                       "patched ROM should have CRC32 {:08X} but has {:08X}",
+                       expected,
+                       actual)
+            }
+            PatchError::PatchChecksumMismatch { expected, actual } => {
+                write!(f,
+                       "patch file is corrupt, CRC32 should be {:08X} but is {:08X}",
+                       expected,
+                       actual)
+            }
+            PatchError::InvalidCommand(ref msg) => write!(f, "invalid patch: {}", msg),
+            PatchError::TargetTooLarge(size) => {
+                write!(f, "patch would produce a {} byte ROM, too large for a Game Boy", size)
+            }
+        }
+    }
+}
+
+impl Error for PatchError {}
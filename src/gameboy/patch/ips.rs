@@ -0,0 +1,44 @@
+// IPS - a list of (offset, bytes) records with run-length encoding and no checksums
+
+use gameboy::patch::PatchError;
+use gameboy::patch::reader::PatchReader;
+
+const EOF_MARKER: usize = 0x454F46; // "EOF"
+
+pub fn apply(rom: &[u8], patch: &[u8]) -> Result<Vec<u8>, PatchError> {
+    let mut output = rom.to_vec();
+    let mut reader = PatchReader::new(patch, 0x05);
+
+    loop {
+        let offset = reader.read_u24_be()?;
+        if offset == EOF_MARKER {
+            break;
+        }
+
+        let size = reader.read_u16_be()?;
+        if size == 0x00 {
+            // RLE record - a single byte repeated
+            let count = reader.read_u16_be()?;
+            let value = reader.read_u8()?;
+            write(&mut output, offset, &vec![value; count]);
+        } else {
+            let bytes = reader.read_bytes(size)?;
+            write(&mut output, offset, bytes);
+        }
+    }
+
+    // Some patchers append the size to truncate the output to after the EOF marker
+    if let Ok(len) = reader.read_u24_be() {
+        output.truncate(len);
+    }
+
+    Ok(output)
+}
+
+fn write(output: &mut Vec<u8>, offset: usize, bytes: &[u8]) {
+    if output.len() < offset + bytes.len() {
+        output.resize(offset + bytes.len(), 0x00);
+    }
+
+    output[offset..offset + bytes.len()].copy_from_slice(bytes);
+}
@@ -0,0 +1,141 @@
+mod bps;
+mod error;
+mod ips;
+mod reader;
+mod ups;
+
+use std::path::{Path, PathBuf};
+
+pub use self::error::PatchError;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PatchFormat {
+    Ips,
+    Ups,
+    Bps,
+}
+
+impl PatchFormat {
+    pub fn detect(patch: &[u8]) -> Option<PatchFormat> {
+        if patch.starts_with(b"PATCH") {
+            Some(PatchFormat::Ips)
+        } else if patch.starts_with(b"UPS1") {
+            Some(PatchFormat::Ups)
+        } else if patch.starts_with(b"BPS1") {
+            Some(PatchFormat::Bps)
+        } else {
+            None
+        }
+    }
+}
+
+pub fn apply_patch(rom: &[u8], patch: &[u8]) -> Result<Vec<u8>, PatchError> {
+    match PatchFormat::detect(patch) {
+        Some(PatchFormat::Ips) => ips::apply(rom, patch),
+        Some(PatchFormat::Ups) => ups::apply(rom, patch),
+        Some(PatchFormat::Bps) => bps::apply(rom, patch),
+        None => Err(PatchError::UnknownFormat),
+    }
+}
+
+// Looks for game.ips, game.ups or game.bps sitting next to game.gb
+pub fn find_adjacent_patch<P>(rom_path: P) -> Option<PathBuf>
+    where P: AsRef<Path>
+{
+    ["ips", "ups", "bps"]
+        .iter()
+        .map(|ext| rom_path.as_ref().with_extension(ext))
+        .find(|p| p.exists())
+}
+
+#[cfg(test)]
+mod tests {
+    use byteorder::{ByteOrder, LittleEndian};
+
+    use gameboy::crc32::crc32;
+    use super::{apply_patch, PatchError};
+
+    fn varint(mut data: usize) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        loop {
+            let x = (data & 0x7F) as u8;
+            data >>= 0x07;
+            if data == 0x00 {
+                bytes.push(0x80 | x);
+                return bytes;
+            }
+            bytes.push(x);
+            data -= 0x01;
+        }
+    }
+
+    // Wraps `body` in a header and a footer with valid source and patch CRC32s
+    fn patch(magic: &[u8], body: &[u8], rom: &[u8], target_crc: u32) -> Vec<u8> {
+        let mut patch = magic.to_vec();
+        patch.extend_from_slice(body);
+
+        let mut crc = [0x00; 0x04];
+        LittleEndian::write_u32(&mut crc, crc32(rom));
+        patch.extend_from_slice(&crc);
+        LittleEndian::write_u32(&mut crc, target_crc);
+        patch.extend_from_slice(&crc);
+        let patch_crc = crc32(&patch);
+        LittleEndian::write_u32(&mut crc, patch_crc);
+        patch.extend_from_slice(&crc);
+
+        patch
+    }
+
+    #[test]
+    fn bps_target_read() {
+        let rom = [0x00; 0x04];
+        let mut body = [varint(0x04), varint(0x02), varint(0x00), varint(0x05)].concat();
+        body.extend_from_slice(b"hi");
+
+        let output = apply_patch(&rom, &patch(b"BPS1", &body, &rom, crc32(b"hi"))).unwrap();
+
+        assert_eq!(b"hi", &output[..]);
+    }
+
+    #[test]
+    fn huge_target_size_is_rejected() {
+        let rom = [0x00; 0x04];
+        let huge = usize::max_value() >> 0x01;
+
+        let bps = [varint(0x04), varint(huge), varint(0x00)].concat();
+        let ups = [varint(0x04), varint(huge)].concat();
+
+        assert_eq!(Err(PatchError::TargetTooLarge(huge)),
+                   apply_patch(&rom, &patch(b"BPS1", &bps, &rom, 0x00)));
+        assert_eq!(Err(PatchError::TargetTooLarge(huge)),
+                   apply_patch(&rom, &patch(b"UPS1", &ups, &rom, 0x00)));
+    }
+
+    #[test]
+    fn huge_lengths_are_errors() {
+        let rom = [0x00; 0x04];
+        let huge = usize::max_value() - 0x10;
+
+        // Metadata that runs off the end of the patch
+        let metadata = [varint(0x04), varint(0x04), varint(huge)].concat();
+        assert_eq!(Err(PatchError::UnexpectedEof),
+                   apply_patch(&rom, &patch(b"BPS1", &metadata, &rom, 0x00)));
+
+        // A target copy that would write far past the target size
+        let copy = [varint(0x04), varint(0x04), varint(0x00), varint(huge & !0x03 | 0x03)].concat();
+        match apply_patch(&rom, &patch(b"BPS1", &copy, &rom, 0x00)) {
+            Err(PatchError::InvalidCommand(_)) => {}
+            other => panic!("expected an invalid command, got {:?}", other),
+        }
+
+        // A UPS offset that overflows
+        let mut ups = [varint(0x04), varint(0x04), varint(0x00)].concat();
+        ups.push(0x00);
+        ups.extend(varint(usize::max_value()));
+        ups.push(0x00);
+        match apply_patch(&rom, &patch(b"UPS1", &ups, &rom, 0x00)) {
+            Err(PatchError::InvalidCommand(_)) => {}
+            other => panic!("expected an invalid command, got {:?}", other),
+        }
+    }
+}
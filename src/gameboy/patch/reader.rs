@@ -0,0 +1,130 @@
+use byteorder::{ByteOrder, LittleEndian};
+
+use gameboy::crc32::crc32;
+use gameboy::patch::PatchError;
+
+// The largest ROM any mapper can address (MBC5, 512 banks of 16KB). Sizes read from a patch
+// are checked against this before anything is allocated for them.
+pub const MAX_TARGET_SIZE: usize = 0x800000;
+
+pub struct PatchReader<'a> {
+    data: &'a [u8],
+    pub pos: usize,
+}
+
+impl<'a> PatchReader<'a> {
+    pub fn new(data: &'a [u8], pos: usize) -> PatchReader<'a> {
+        PatchReader {
+            data: data,
+            pos: pos,
+        }
+    }
+
+    pub fn read_u8(&mut self) -> Result<u8, PatchError> {
+        let b = *self.data.get(self.pos).ok_or(PatchError::UnexpectedEof)?;
+        self.pos += 0x01;
+
+        Ok(b)
+    }
+
+    pub fn read_bytes(&mut self, len: usize) -> Result<&'a [u8], PatchError> {
+        let end = match self.pos.checked_add(len) {
+            Some(end) if end <= self.data.len() => end,
+            _ => return Err(PatchError::UnexpectedEof),
+        };
+
+        let bytes = &self.data[self.pos..end];
+        self.pos = end;
+
+        Ok(bytes)
+    }
+
+    pub fn read_u16_be(&mut self) -> Result<usize, PatchError> {
+        let b = self.read_bytes(0x02)?;
+        Ok(((b[0] as usize) << 0x08) | b[1] as usize)
+    }
+
+    pub fn read_u24_be(&mut self) -> Result<usize, PatchError> {
+        let b = self.read_bytes(0x03)?;
+        Ok(((b[0] as usize) << 0x10) | ((b[1] as usize) << 0x08) | b[2] as usize)
+    }
+
+    // The variable-length integer encoding shared by UPS and BPS
+    pub fn read_varint(&mut self) -> Result<usize, PatchError> {
+        let mut data = 0usize;
+        let mut shift = 1usize;
+
+        loop {
+            let x = self.read_u8()?;
+            data = data.wrapping_add((x as usize & 0x7F).wrapping_mul(shift));
+            if x & 0x80 == 0x80 {
+                break;
+            }
+            shift <<= 0x07;
+            data = data.wrapping_add(shift);
+        }
+
+        Ok(data)
+    }
+
+    // A varint that gives the size of the patched ROM
+    pub fn read_target_size(&mut self) -> Result<usize, PatchError> {
+        let size = self.read_varint()?;
+        if size > MAX_TARGET_SIZE {
+            return Err(PatchError::TargetTooLarge(size));
+        }
+
+        Ok(size)
+    }
+}
+
+// UPS and BPS both end in CRC32s of the source, the target and the patch itself
+pub struct Footer {
+    pub source_crc: u32,
+    pub target_crc: u32,
+}
+
+pub fn read_footer(patch: &[u8]) -> Result<Footer, PatchError> {
+    if patch.len() < 0x10 {
+        return Err(PatchError::UnexpectedEof);
+    }
+
+    let footer = &patch[patch.len() - 0x0C..];
+    let patch_crc = LittleEndian::read_u32(&footer[0x08..]);
+    let actual = crc32(&patch[..patch.len() - 0x04]);
+    if patch_crc != actual {
+        return Err(PatchError::PatchChecksumMismatch {
+            expected: patch_crc,
+            actual: actual,
+        });
+    }
+
+    Ok(Footer {
+        source_crc: LittleEndian::read_u32(footer),
+        target_crc: LittleEndian::read_u32(&footer[0x04..]),
+    })
+}
+
+pub fn verify_source(footer: &Footer, rom: &[u8]) -> Result<(), PatchError> {
+    let actual = crc32(rom);
+    if footer.source_crc != actual {
+        return Err(PatchError::SourceChecksumMismatch {
+            expected: footer.source_crc,
+            actual: actual,
+        });
+    }
+
+    Ok(())
+}
+
+pub fn verify_target(footer: &Footer, target: &[u8]) -> Result<(), PatchError> {
+    let actual = crc32(target);
+    if footer.target_crc != actual {
+        return Err(PatchError::TargetChecksumMismatch {
+            expected: footer.target_crc,
+            actual: actual,
+        });
+    }
+
+    Ok(())
+}
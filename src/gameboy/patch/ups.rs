@@ -0,0 +1,48 @@
+// UPS - XOR runs against the source ROM, validated by CRC32
+
+use gameboy::patch::PatchError;
+use gameboy::patch::reader::{self, PatchReader};
+
+pub fn apply(rom: &[u8], patch: &[u8]) -> Result<Vec<u8>, PatchError> {
+    let footer = reader::read_footer(patch)?;
+    reader::verify_source(&footer, rom)?;
+
+    let mut reader = PatchReader::new(patch, 0x04);
+    let source_size = reader.read_varint()?;
+    let target_size = reader.read_target_size()?;
+
+    if source_size != rom.len() {
+        return Err(PatchError::InvalidCommand(format!(
+            "patch expects a {} byte ROM but this ROM is {} bytes",
+            source_size,
+            rom.len()
+        )));
+    }
+
+    let mut output = rom.to_vec();
+    output.resize(target_size, 0x00);
+
+    let end = patch.len() - 0x0C;
+    let mut offset = 0usize;
+    while reader.pos < end {
+        offset = offset.checked_add(reader.read_varint()?)
+            .ok_or_else(|| PatchError::InvalidCommand("XOR run starts out of range".into()))?;
+
+        // XOR until the terminating zero byte, which also consumes one output byte
+        loop {
+            let x = reader.read_u8()?;
+            if offset < target_size {
+                output[offset] ^= x;
+            }
+            offset = offset.saturating_add(0x01);
+
+            if x == 0x00 {
+                break;
+            }
+        }
+    }
+
+    reader::verify_target(&footer, &output)?;
+
+    Ok(output)
+}
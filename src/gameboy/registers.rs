@@ -17,24 +17,20 @@ pub struct Registers {
 }
 
 impl Registers {
-    pub fn new(gameboy_color: bool) -> Registers {
-        // Taken from http://www.devrs.com/gb/files/gbspec.txt
-        let a = match gameboy_color {
-            true => 0x11,
-            false => 0x01,
-        };
-
+    // Power-on state. The boot ROM sets everything up from here, see `HardwareModel` for
+    // what it leaves behind.
+    pub fn new() -> Registers {
         Registers {
-            a: a,
+            a: 0x00,
             b: 0x00,
-            c: 0x13,
+            c: 0x00,
             d: 0x00,
-            e: 0xD8,
-            h: 0x01,
-            l: 0x4D,
+            e: 0x00,
+            h: 0x00,
+            l: 0x00,
 
             pc: 0x00,
-            sp: 0xFFFE,
+            sp: 0x00,
 
             div: 0x00,
 
@@ -0,0 +1,124 @@
+// clrmamepro DATs, a nested list of key/value pairs:
+//
+//   game (
+//       name "Game (USA, Europe) (Rev 1)"
+//       rom ( name "Game (USA, Europe) (Rev 1).gb" size 32768 crc ... sha1 ... flags verified )
+//   )
+
+use gameboy::romdb::{parse_hex, parse_sha1, DatError, DumpStatus, RomEntry};
+
+pub fn parse(text: &str) -> Result<Vec<RomEntry>, DatError> {
+    let tokens = tokenize(text)?;
+    let mut entries = Vec::new();
+
+    let mut i = 0;
+    while i < tokens.len() {
+        let end = group_end(&tokens, i + 1)?;
+        if tokens[i] == "game" {
+            parse_game(&tokens[i + 2..end], &mut entries)?;
+        }
+        i = end + 1;
+    }
+
+    Ok(entries)
+}
+
+fn parse_game(tokens: &[String], entries: &mut Vec<RomEntry>) -> Result<(), DatError> {
+    let mut name = None;
+    let mut roms = Vec::new();
+
+    let mut i = 0;
+    while i + 1 < tokens.len() {
+        if tokens[i + 1] == "(" {
+            let end = group_end(tokens, i + 1)?;
+            if tokens[i] == "rom" {
+                roms.push(&tokens[i + 2..end]);
+            }
+            i = end + 1;
+        } else {
+            if tokens[i] == "name" {
+                name = Some(tokens[i + 1].clone());
+            }
+            i += 2;
+        }
+    }
+
+    let name = name.ok_or_else(|| DatError::Parse("game has no name".into()))?;
+    for rom in roms {
+        let value = |key: &str| {
+            rom.iter().position(|t| t == key).and_then(|i| rom.get(i + 1))
+        };
+
+        let crc = value("crc").ok_or_else(|| DatError::Parse(format!("{} has no crc", name)))?;
+        let status = match value("flags").map(|s| s.as_str()) {
+            Some("verified") => DumpStatus::Verified,
+            Some("baddump") => DumpStatus::BadDump,
+            _ => DumpStatus::Unverified,
+        };
+
+        entries.push(RomEntry::new(name.clone(),
+                                   value("size").and_then(|s| s.parse().ok()).unwrap_or(0),
+                                   parse_hex(crc)?,
+                                   value("sha1").map(|s| parse_sha1(s)).unwrap_or(Ok(None))?,
+                                   status));
+    }
+
+    Ok(())
+}
+
+// Index of the ")" closing the "(" at tokens[start]
+fn group_end(tokens: &[String], start: usize) -> Result<usize, DatError> {
+    if tokens.get(start).map(|t| t.as_str()) != Some("(") {
+        return Err(DatError::Parse("expected (".into()));
+    }
+
+    let mut depth = 0;
+    for (i, t) in tokens.iter().enumerate().skip(start) {
+        match t.as_str() {
+            "(" => depth += 1,
+            ")" => depth -= 1,
+            _ => (),
+        }
+        if depth == 0 {
+            return Ok(i);
+        }
+    }
+
+    Err(DatError::Parse("unbalanced parentheses".into()))
+}
+
+fn tokenize(text: &str) -> Result<Vec<String>, DatError> {
+    let mut tokens = Vec::new();
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '(' | ')' => tokens.push(c.to_string()),
+            '"' => {
+                let mut s = String::new();
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some(c) => s.push(c),
+                        None => return Err(DatError::Parse("unterminated string".into())),
+                    }
+                }
+                tokens.push(s);
+            }
+            c if c.is_whitespace() => (),
+            c => {
+                let mut s = c.to_string();
+                while let Some(&c) = chars.peek() {
+                    if c.is_whitespace() || c == '(' || c == ')' {
+                        break;
+                    }
+                    s.push(c);
+                    chars.next();
+                }
+                tokens.push(s);
+            }
+        }
+    }
+
+    Ok(tokens)
+}
@@ -0,0 +1,28 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+#[derive(Debug)]
+pub enum DatError {
+    UnknownFormat,
+    Parse(String),
+    Io(io::Error),
+}
+
+impl fmt::Display for DatError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DatError::UnknownFormat => write!(f, "not an XML or clrmamepro DAT file"),
+            DatError::Parse(ref msg) => write!(f, "invalid DAT file: {}", msg),
+            DatError::Io(ref e) => write!(f, "could not read DAT file: {}", e),
+        }
+    }
+}
+
+impl Error for DatError {}
+
+impl From<io::Error> for DatError {
+    fn from(e: io::Error) -> DatError {
+        DatError::Io(e)
+    }
+}
@@ -0,0 +1,176 @@
+// Identifies ROMs by hash against No-Intro style DAT files, since header titles are
+// truncated to 11-16 characters and often shared between revisions. A small subset of the
+// No-Intro Game Boy DAT is bundled, the full DAT can be loaded from the command line.
+
+mod clrmamepro;
+mod error;
+mod xml;
+
+pub use self::error::DatError;
+
+use std::fmt;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+use gameboy::crc32::crc32;
+use gameboy::sha1::sha1;
+
+const BUNDLED_DAT: &'static str = include_str!("gb.dat");
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DumpStatus {
+    Verified,
+    Unverified,
+    BadDump,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RomEntry {
+    pub name: String,
+    pub region: Option<String>,
+    pub revision: Option<String>,
+    pub status: DumpStatus,
+    pub size: usize,
+    pub crc32: u32,
+    pub sha1: Option<[u8; 20]>,
+}
+
+impl RomEntry {
+    pub fn new(name: String,
+               size: usize,
+               crc32: u32,
+               sha1: Option<[u8; 20]>,
+               status: DumpStatus)
+               -> RomEntry {
+        // No-Intro names are "Title (Region) (Languages) (Rev N) ...", the region always first
+        let tags: Vec<&str> = name.split('(')
+            .skip(1)
+            .filter_map(|t| t.split(')').next())
+            .collect();
+        let region = tags.first().map(|t| t.to_string());
+        let revision = tags.iter().find(|t| t.starts_with("Rev ")).map(|t| t.to_string());
+
+        RomEntry {
+            region: region,
+            revision: revision,
+            // GoodTools style names flag bad dumps with [b] rather than a status
+            status: if name.contains("[b") { DumpStatus::BadDump } else { status },
+            name: name,
+            size: size,
+            crc32: crc32,
+            sha1: sha1,
+        }
+    }
+}
+
+impl fmt::Display for RomEntry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Database name:    {}", self.name)?;
+        writeln!(f,
+                 "Region:           {}",
+                 self.region.as_ref().map(|r| r.as_str()).unwrap_or("Unknown"))?;
+        writeln!(f,
+                 "Revision:         {}",
+                 self.revision.as_ref().map(|r| r.as_str()).unwrap_or("Original"))?;
+        write!(f, "Dump status:      {:?}", self.status)
+    }
+}
+
+pub struct RomDatabase {
+    entries: Vec<RomEntry>,
+}
+
+impl RomDatabase {
+    pub fn new() -> RomDatabase {
+        RomDatabase { entries: Vec::new() }
+    }
+
+    pub fn bundled() -> RomDatabase {
+        let mut db = RomDatabase::new();
+        db.add_dat(BUNDLED_DAT).expect("err: bundled DAT file is invalid");
+        db
+    }
+
+    pub fn load_file<P>(&mut self, p: P) -> Result<usize, DatError>
+        where P: AsRef<Path>
+    {
+        let mut contents = String::new();
+        File::open(p)?.read_to_string(&mut contents)?;
+
+        self.add_dat(&contents)
+    }
+
+    // Entries added later win, so user-supplied DATs override the bundled one
+    pub fn add_dat(&mut self, text: &str) -> Result<usize, DatError> {
+        let trimmed = text.trim_start();
+        let entries = if trimmed.starts_with('<') {
+            xml::parse(text)?
+        } else if trimmed.starts_with("clrmamepro") || trimmed.starts_with("game") {
+            clrmamepro::parse(text)?
+        } else {
+            return Err(DatError::UnknownFormat);
+        };
+
+        let count = entries.len();
+        self.entries.splice(0..0, entries);
+
+        Ok(count)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    // The CRC-32 narrows it down, SHA-1 settles it when the DAT has one
+    pub fn identify(&self, rom: &[u8]) -> Option<&RomEntry> {
+        let crc = crc32(rom);
+        let mut digest = None;
+
+        self.entries.iter().find(|e| {
+            if e.crc32 != crc || (e.size != 0 && e.size != rom.len()) {
+                return false;
+            }
+
+            match e.sha1 {
+                Some(expected) => *digest.get_or_insert_with(|| sha1(rom)) == expected,
+                None => true,
+            }
+        })
+    }
+}
+
+pub fn parse_hex(s: &str) -> Result<u32, DatError> {
+    u32::from_str_radix(s, 16).map_err(|_| DatError::Parse(format!("invalid CRC {}", s)))
+}
+
+pub fn parse_sha1(s: &str) -> Result<Option<[u8; 20]>, DatError> {
+    if s.len() != 40 {
+        return Err(DatError::Parse(format!("invalid SHA-1 {}", s)));
+    }
+
+    let mut digest = [0x00; 20];
+    for (i, b) in digest.iter_mut().enumerate() {
+        *b = u8::from_str_radix(&s[i * 2..i * 2 + 2], 16)
+            .map_err(|_| DatError::Parse(format!("invalid SHA-1 {}", s)))?;
+    }
+
+    Ok(Some(digest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RomDatabase;
+
+    #[test]
+    fn bundled_dat_parses() {
+        let db = RomDatabase::bundled();
+
+        let tetris = db.entries.iter().find(|e| e.name == "Tetris (World) (Rev A)").unwrap();
+
+        assert_eq!(0x46DF91AD, tetris.crc32);
+        assert_eq!(Some("World"), tetris.region.as_ref().map(|r| r.as_str()));
+        assert_eq!(Some("Rev A"), tetris.revision.as_ref().map(|r| r.as_str()));
+        assert!(db.identify(&[0x00; 0x8000]).is_none());
+    }
+}
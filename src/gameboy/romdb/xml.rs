@@ -0,0 +1,63 @@
+// Logiqx XML DATs, the format No-Intro publishes. Only <game> elements and the
+// attributes of their <rom> children are read, everything else is skipped.
+
+use gameboy::romdb::{parse_hex, parse_sha1, DatError, DumpStatus, RomEntry};
+
+pub fn parse(text: &str) -> Result<Vec<RomEntry>, DatError> {
+    let mut entries = Vec::new();
+    let mut game: Option<String> = None;
+
+    let mut rest = text;
+    while let Some(start) = rest.find('<') {
+        let end = rest[start..]
+            .find('>')
+            .ok_or_else(|| DatError::Parse("unterminated tag".into()))?;
+        let tag = &rest[start + 1..start + end];
+        rest = &rest[start + end + 1..];
+
+        if tag.starts_with("game ") || tag.starts_with("machine ") {
+            game = attribute(tag, "name");
+        } else if tag.starts_with("/game") || tag.starts_with("/machine") {
+            game = None;
+        } else if tag.starts_with("rom ") {
+            let name = game.clone()
+                .ok_or_else(|| DatError::Parse("<rom> outside of a <game>".into()))?;
+            entries.push(rom_entry(name, tag)?);
+        }
+    }
+
+    Ok(entries)
+}
+
+fn rom_entry(name: String, tag: &str) -> Result<RomEntry, DatError> {
+    let crc = attribute(tag, "crc")
+        .ok_or_else(|| DatError::Parse(format!("{} has no crc", name)))?;
+    let size = attribute(tag, "size").and_then(|s| s.parse().ok()).unwrap_or(0);
+    let status = match attribute(tag, "status").as_ref().map(|s| s.as_str()) {
+        Some("verified") => DumpStatus::Verified,
+        Some("baddump") => DumpStatus::BadDump,
+        _ => DumpStatus::Unverified,
+    };
+
+    Ok(RomEntry::new(name,
+                     size,
+                     parse_hex(&crc)?,
+                     attribute(tag, "sha1").map(|s| parse_sha1(&s)).unwrap_or(Ok(None))?,
+                     status))
+}
+
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let key = format!(" {}=\"", name);
+    let start = tag.find(&key)? + key.len();
+    let end = tag[start..].find('"')?;
+
+    Some(unescape(&tag[start..start + end]))
+}
+
+fn unescape(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
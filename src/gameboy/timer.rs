@@ -1,21 +1,27 @@
-use gameboy::{MAX_CPU_CYCLES, Interconnect, Irq, Interrupt};
+use gameboy::{Irq, Interrupt, M_CYCLE};
 
 pub struct Timer {
-    div: usize,
+    // Counts every clock cycle, DIV is the upper byte
+    counter: u16,
     tima: u8,
     tma: u8,
     tac: u8,
-    cycles: usize,
+
+    // TIMA overflowed during the last M-cycle and reads as 0x00 until TMA is loaded
+    overflowed: bool,
+    // TMA is being loaded into TIMA during this M-cycle
+    reloading: bool,
 }
 
 impl Timer {
     pub fn new() -> Timer {
         Timer {
-            div: 0x00,
+            counter: 0x00,
             tima: 0x00,
             tma: 0x00,
             tac: 0x00,
-            cycles: MAX_CPU_CYCLES / 0x1000,
+            overflowed: false,
+            reloading: false,
         }
     }
 
@@ -24,71 +30,213 @@ impl Timer {
     }
 
     pub fn step(&mut self, irq: &mut Irq, cycles: usize) -> Result<(), String> {
-        // DIV register increments regardless
-        self.inc_div_register(cycles);
+        for _ in 0..cycles / M_CYCLE {
+            self.reloading = false;
 
-        if !self.enabled() {
-            return Ok(());
-        }
+            // The reload and interrupt happen one M-cycle after the overflow
+            if self.overflowed {
+                self.overflowed = false;
+                self.reloading = true;
+                self.tima = self.tma;
+                irq.request(Interrupt::Timer);
+            }
 
-        self.inc_tima_register(irq, cycles);
+            let counter = self.counter.wrapping_add(M_CYCLE as u16);
+            self.set_counter(counter);
+        }
 
         Ok(())
     }
 
     pub fn read_u8(&self, addr: u16) -> u8 {
         match addr {
-            0x04 => self.div as u8,
+            0x04 => (self.counter >> 0x08) as u8,
             0x05 => self.tima,
             0x06 => self.tma,
-            0x07 => self.tac,
+            0x07 => self.tac | 0xF8,
             _ => panic!("read timer memory that is unmapped"),
         }
     }
 
     pub fn write_u8(&mut self, addr: u16, val: u8) {
         match addr {
-            0x04 => self.div = 0,
-            0x05 => self.tima = val,
-            0x06 => self.tma = val,
-            0x07 => self.tac = val,
+            // Resetting the counter can cause a falling edge, and so an extra TIMA tick
+            0x04 => self.set_counter(0x00),
+            0x05 => {
+                // Writes are ignored while TMA is being loaded, but cancel a pending reload
+                if !self.reloading {
+                    self.tima = val;
+                    self.overflowed = false;
+                }
+            }
+            0x06 => {
+                self.tma = val;
+                if self.reloading {
+                    self.tima = val;
+                }
+            }
+            0x07 => {
+                // Switching the timer off or to another bit can also cause a falling edge
+                let signal = self.signal();
+                self.tac = val & 0x07;
+                self.tick_on_falling_edge(signal);
+            }
             _ => panic!("read timer memory that is unmapped"),
         }
     }
 
+    // Only used to recreate the state the boot ROM leaves behind. Writes to DIV reset it.
+    pub fn set_div(&mut self, div: u8) {
+        self.counter = (div as u16) << 0x08;
+    }
+
+    fn set_counter(&mut self, counter: u16) {
+        let signal = self.signal();
+        self.counter = counter;
+        self.tick_on_falling_edge(signal);
+    }
+
+    fn tick_on_falling_edge(&mut self, signal: bool) {
+        if signal && !self.signal() {
+            self.inc_tima_register();
+        }
+    }
+
+    // TIMA ticks whenever this goes from high to low
+    fn signal(&self) -> bool {
+        let bit = match self.tac & 0x03 {
+            0x00 => 0x09, // 4096hz
+            0x01 => 0x03, // 262144hz
+            0x02 => 0x05, // 65536hz
+            _ => 0x07, // 16384hz
+        };
+
+        self.enabled() && (self.counter >> bit) & 0x01 == 0x01
+    }
+
     fn enabled(&self) -> bool {
         self.tac & 0x04 == 0x04
     }
 
-    fn inc_div_register(&mut self, cycles: usize) {
-        self.div += cycles;
-        if self.div >= 0xFF {
-            self.div = 0x00;
+    fn inc_tima_register(&mut self) {
+        let (tima, overflowed) = self.tima.overflowing_add(0x01);
+        self.tima = tima;
+
+        if overflowed {
+            self.overflowed = true;
         }
     }
+}
 
-    fn inc_tima_register(&mut self, irq: &mut Irq, cycles: usize) {
-        if self.enabled() {
-            if self.cycles < cycles {
-                self.set_timer_frequency();
-                if self.tima == 0xFF {
-                    self.tima = self.tma; // set the TIMA register to be whatever is in the modulo TMA register
-                    irq.request(Interrupt::Timer); // it overflowed, request a timer interrupt
-                }
-                self.tima.wrapping_add(0x01);
-            }
-            self.cycles = self.cycles.wrapping_sub(cycles);
+#[cfg(test)]
+mod tests {
+    use gameboy::{Irq, M_CYCLE};
+    use super::Timer;
+
+    fn tick(timer: &mut Timer, irq: &mut Irq, m_cycles: usize) {
+        for _ in 0..m_cycles {
+            timer.step(irq, M_CYCLE).unwrap();
         }
     }
 
-    fn set_timer_frequency(&mut self) {
-        let speed = self.tac & 0x03;
-        self.cycles = match speed {
-            0x00 => MAX_CPU_CYCLES / 0x1000,
-            0x01 => MAX_CPU_CYCLES / 0x40000,
-            0x02 => MAX_CPU_CYCLES / 0x10000,
-            0x03 => MAX_CPU_CYCLES / 0x4000,
-            _ => 0,
-        };
+    // TAC 0x05 ticks TIMA every 4 M-cycles. Returns a timer whose next tick overflows.
+    fn about_to_overflow(irq: &mut Irq) -> Timer {
+        let mut timer = Timer::new();
+        timer.write_u8(0x07, 0x05);
+        timer.write_u8(0x05, 0xFF);
+        timer.write_u8(0x06, 0x42);
+        tick(&mut timer, irq, 0x03);
+        timer
+    }
+
+    #[test]
+    fn div_counts_every_64_m_cycles() {
+        let mut irq = Irq::new();
+        let mut timer = Timer::new();
+
+        tick(&mut timer, &mut irq, 0x40);
+        assert_eq!(timer.read_u8(0x04), 0x01);
+
+        timer.write_u8(0x04, 0xAB);
+        assert_eq!(timer.read_u8(0x04), 0x00);
+    }
+
+    #[test]
+    fn overflow_reloads_tma_an_m_cycle_later() {
+        let mut irq = Irq::new();
+        let mut timer = about_to_overflow(&mut irq);
+
+        tick(&mut timer, &mut irq, 0x01);
+        assert_eq!(timer.read_u8(0x05), 0x00);
+        assert_eq!(irq.request_flag, 0x00);
+
+        tick(&mut timer, &mut irq, 0x01);
+        assert_eq!(timer.read_u8(0x05), 0x42);
+        assert_eq!(irq.request_flag, 0x04);
+    }
+
+    #[test]
+    fn tima_write_cancels_a_pending_reload() {
+        let mut irq = Irq::new();
+        let mut timer = about_to_overflow(&mut irq);
+
+        tick(&mut timer, &mut irq, 0x01);
+        timer.write_u8(0x05, 0x33);
+        tick(&mut timer, &mut irq, 0x01);
+
+        assert_eq!(timer.read_u8(0x05), 0x33);
+        assert_eq!(irq.request_flag, 0x00);
+    }
+
+    #[test]
+    fn writes_during_the_reload_cycle() {
+        let mut irq = Irq::new();
+        let mut timer = about_to_overflow(&mut irq);
+        tick(&mut timer, &mut irq, 0x02);
+
+        // TIMA writes are ignored, TMA writes go straight through to TIMA
+        timer.write_u8(0x05, 0x10);
+        assert_eq!(timer.read_u8(0x05), 0x42);
+        timer.write_u8(0x06, 0x20);
+        assert_eq!(timer.read_u8(0x05), 0x20);
+
+        // Only for that one M-cycle
+        tick(&mut timer, &mut irq, 0x01);
+        timer.write_u8(0x05, 0x10);
+        assert_eq!(timer.read_u8(0x05), 0x10);
+    }
+
+    #[test]
+    fn resetting_div_can_tick_tima() {
+        let mut irq = Irq::new();
+        let mut timer = Timer::new();
+        timer.write_u8(0x07, 0x05);
+
+        // Bit 3 of the counter is set after 2 M-cycles, and clearing it is a falling edge
+        tick(&mut timer, &mut irq, 0x02);
+        assert_eq!(timer.read_u8(0x05), 0x00);
+        timer.write_u8(0x04, 0x00);
+        assert_eq!(timer.read_u8(0x05), 0x01);
+
+        // Bit 3 is clear after 1 M-cycle, so there's no edge
+        tick(&mut timer, &mut irq, 0x01);
+        timer.write_u8(0x04, 0x00);
+        assert_eq!(timer.read_u8(0x05), 0x01);
+    }
+
+    #[test]
+    fn changing_tac_can_tick_tima() {
+        let mut irq = Irq::new();
+        let mut timer = Timer::new();
+        timer.write_u8(0x07, 0x05);
+        tick(&mut timer, &mut irq, 0x02);
+
+        // Switching to bit 9, which is clear, or disabling the timer are both falling edges
+        timer.write_u8(0x07, 0x04);
+        assert_eq!(timer.read_u8(0x05), 0x01);
+
+        timer.write_u8(0x07, 0x05);
+        timer.write_u8(0x07, 0x01);
+        assert_eq!(timer.read_u8(0x05), 0x02);
     }
 }
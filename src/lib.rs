@@ -4,11 +4,13 @@ extern crate clap;
 #[macro_use]
 extern crate conrod;
 extern crate find_folder;
+extern crate flate2;
 extern crate gfx_core;
 extern crate gfx_device_gl;
 extern crate image;
 extern crate piston_window;
 extern crate rand;
+extern crate zip;
 
 #[macro_use]
 pub mod gameboy;
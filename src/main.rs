@@ -3,12 +3,14 @@ extern crate clap;
 #[macro_use]
 extern crate conrod;
 extern crate find_folder;
+extern crate flate2;
 extern crate gfx_core;
 extern crate gfx_device_gl;
 extern crate graphics;
 extern crate image;
 extern crate piston_window;
 extern crate rand;
+extern crate zip;
 
 use image::Pixel;
 use clap::{App, Arg};
@@ -19,13 +21,15 @@ use piston_window::OpenGL;
 use std::fs::File;
 use std::io::Read;
 use std::ops::{Deref, DerefMut};
+use std::path::{Path, PathBuf};
 
 pub mod gameboy;
 
-use gameboy::{Cartridge, CpuSpeed, Frame, JoypadButton, Ui};
+use gameboy::{Cartridge, CpuSpeed, Frame, HardwareModel, JoypadButton, Renderer, Ui};
 
 const WINDOW_WIDTH: u32 = 180;
 const WINDOW_HEIGHT: u32 = 180;
+const SAVE_INTERVAL_FRAMES: usize = 60 * 5; // Flush battery RAM roughly every 5 seconds
 
 fn main() {
     let matches = App::new("chemboy")
@@ -38,7 +42,61 @@ fn main() {
                 .long("rom")
                 .value_name("ROM_PATH")
                 .required(true)
-                .help("Path to a Gameboy or Gameboy Color ROM")
+                .help("Path to a Gameboy or Gameboy Color ROM, optionally zipped or gzipped")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("rom-entry")
+                .long("rom-entry")
+                .value_name("ENTRY")
+                .help("Name of the ROM inside a zip archive. Defaults to the first .gb, .gbc \
+                       or .sgb file")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("patch")
+                .short("p")
+                .long("patch")
+                .value_name("PATCH_PATH")
+                .help("Path to an IPS, UPS or BPS patch to apply to the ROM. Defaults to a \
+                       patch with the same name as the ROM, if one exists")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("cheats")
+                .short("c")
+                .long("cheats")
+                .value_name("CHEAT_FILE")
+                .help("Path to a file of Game Genie or GameShark codes, one per line")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("camera")
+                .long("camera")
+                .value_name("IMAGE")
+                .help("PNG or JPEG images for the Game Boy Camera to capture. Given more than \
+                       once, each capture moves on to the next image")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1),
+        )
+        .arg(
+            Arg::with_name("dat")
+                .long("dat")
+                .value_name("DAT_FILE")
+                .help("Additional No-Intro XML or clrmamepro DAT file used to identify the ROM. \
+                       Given more than once, later files take priority")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1),
+        )
+        .arg(
+            Arg::with_name("model")
+                .short("m")
+                .long("model")
+                .value_name("MODEL")
+                .help("Hardware to emulate: dmg0, dmg, mgb, sgb, sgb2, cgb or agb. Defaults \
+                       to dmg")
                 .takes_value(true),
         )
         .arg(
@@ -46,6 +104,22 @@ fn main() {
                 .long("disable-boot-rom")
                 .help("Disables the boot rom"),
         )
+        .arg(
+            Arg::with_name("SCANLINE_RENDERER")
+                .long("scanline-renderer")
+                .help("Draws whole lines at once instead of through the pixel FIFO. Faster, \
+                       but mid-line effects are lost"),
+        )
+        .arg(
+            Arg::with_name("FORCE")
+                .long("force")
+                .help("Loads the ROM even if its header checksum is bad"),
+        )
+        .arg(
+            Arg::with_name("INFO")
+                .long("info")
+                .help("Prints the cartridge header details and exits"),
+        )
         .arg(
             Arg::with_name("DEBUG")
                 .long("debug")
@@ -53,18 +127,107 @@ fn main() {
         )
         .get_matches();
 
-    let rom = matches.value_of("rom").unwrap();
-    if rom.len() < 1 {
+    let rom_path = matches.value_of("rom").unwrap();
+    if rom_path.len() < 1 {
         panic!("Must specify ROM to load");
     }
 
     let enable_debugger = matches.is_present("DEBUG");
     let disable_boot_rom = matches.is_present("DISABLE_BOOT_ROM");
+    let model = match matches.value_of("model").unwrap_or("dmg").parse::<HardwareModel>() {
+        Ok(model) => model,
+        Err(e) => {
+            println!("err: {}", e);
+            std::process::exit(1);
+        }
+    };
 
-    let rom = load_rom(rom).unwrap();
-    let cart = Cartridge::with_rom(&rom);
-    let mut gameboy = gameboy::GameBoy::new(false, cart, !disable_boot_rom);
-    let game_title = gameboy.cart_details().game_title.clone();
+    let rom = match gameboy::read_rom(rom_path, matches.value_of("rom-entry")) {
+        Ok(rom) => rom,
+        Err(e) => {
+            println!("err: could not read ROM {}: {}", rom_path, e);
+            std::process::exit(1);
+        }
+    };
+
+    let mut romdb = gameboy::RomDatabase::bundled();
+    for dat in matches.values_of("dat").into_iter().flat_map(|v| v) {
+        if let Err(e) = romdb.load_file(dat) {
+            println!("err: could not load {}: {}", dat, e);
+            std::process::exit(1);
+        }
+    }
+    // DATs list clean dumps, so identify the ROM before any patch is applied
+    let rom_entry = romdb.identify(&rom).cloned();
+
+    let patch_path = matches
+        .value_of("patch")
+        .map(PathBuf::from)
+        .or_else(|| gameboy::find_adjacent_patch(rom_path));
+    let rom = match patch_path {
+        Some(p) => match load_rom(&p).map(|patch| gameboy::apply_patch(&rom, &patch)) {
+            Ok(Ok(patched)) => patched,
+            Ok(Err(e)) => {
+                println!("err: could not apply patch {}: {}", p.display(), e);
+                std::process::exit(1);
+            }
+            Err(e) => {
+                println!("err: could not read patch {}: {}", p.display(), e);
+                std::process::exit(1);
+            }
+        },
+        None => rom,
+    };
+
+    let mut cart = match Cartridge::load(&rom, matches.is_present("FORCE")) {
+        Ok(cart) => cart,
+        Err(e) => {
+            println!("err: could not load ROM {}: {}", rom_path, e);
+            if e.is_warning() {
+                println!("Run with --force to load it anyway");
+            }
+            std::process::exit(1);
+        }
+    };
+    // Named after the file that was opened, so a zipped ROM saves next to its archive
+    let save_path = Path::new(rom_path).with_extension("sav");
+    if let Err(e) = cart.attach_save_file(&save_path) {
+        println!("err: could not load save file {}: {}", save_path.display(), e);
+        std::process::exit(1);
+    }
+    if matches.is_present("INFO") {
+        println!("{}", cart.details);
+        match rom_entry {
+            Some(ref entry) => println!("{}", entry),
+            None => println!("Database name:    Not found"),
+        }
+        return;
+    }
+
+    let mut gameboy = gameboy::GameBoy::new(model, cart, !disable_boot_rom);
+    if matches.is_present("SCANLINE_RENDERER") {
+        gameboy.set_renderer(Renderer::Scanline);
+    }
+    if let Some(cheat_file) = matches.value_of("cheats") {
+        if let Err(e) = gameboy.load_cheat_file(cheat_file) {
+            println!("err: could not load cheats from {}: {}", cheat_file, e);
+            std::process::exit(1);
+        }
+    }
+    if let Some(images) = matches.values_of("camera") {
+        let images: Vec<&str> = images.collect();
+        match gameboy::ImageSource::open(&images) {
+            Ok(source) => gameboy.set_camera_source(Box::new(source)),
+            Err(e) => {
+                println!("err: {}", e);
+                std::process::exit(1);
+            }
+        }
+    }
+    let game_title = match rom_entry {
+        Some(entry) => entry.name,
+        None => gameboy.cart_details().game_title.clone(),
+    };
 
     let mut window = create_window(game_title, enable_debugger);
     let mut factory = window.factory.clone();
@@ -82,6 +245,8 @@ fn main() {
     let mut texture = Texture::from_image(&mut factory, &imgbuf, &TextureSettings::new())
         .expect("err: could not build requested gameboy frame");
 
+    let mut frames = 0;
+    let mut tilt = (0.0, 0.0);
     'start: while let Some(e) = window.next() {
         if let Some(button) = e.press_args() {
             if let Button::Keyboard(key) = button {
@@ -95,6 +260,10 @@ fn main() {
                     Key::Up => gameboy.press(JoypadButton::Up),
                     Key::Down => gameboy.press(JoypadButton::Down),
                     Key::Backspace => gameboy.reset(),
+                    Key::J => tilt.0 = -1.0,
+                    Key::L => tilt.0 = 1.0,
+                    Key::I => tilt.1 = -1.0,
+                    Key::K => tilt.1 = 1.0,
                     _ => (),
                 }
             }
@@ -110,10 +279,18 @@ fn main() {
                     Key::Right => gameboy.unpress(JoypadButton::Right),
                     Key::Up => gameboy.unpress(JoypadButton::Up),
                     Key::Down => gameboy.unpress(JoypadButton::Down),
+                    Key::J | Key::L => tilt.0 = 0.0,
+                    Key::I | Key::K => tilt.1 = 0.0,
                     _ => (),
                 }
             }
         }
+        if let Some(pos) = e.mouse_cursor_args() {
+            // Tilt towards the mouse cursor, relative to the centre of the window
+            tilt.0 = (pos[0] / WINDOW_WIDTH as f64 * 2.0 - 1.0) as f32;
+            tilt.1 = (pos[1] / WINDOW_HEIGHT as f64 * 2.0 - 1.0) as f32;
+        }
+        gameboy.set_tilt(tilt.0, tilt.1);
         /*let ui_event = ui.handle_event(&e);
         match ui_event {
             UIEvent::ThemeSwitched(theme) => gameboy.switch_theme(theme),
@@ -126,9 +303,20 @@ fn main() {
             let (x, y) = get_projection_coordinates();
             image(&texture, c.transform.trans(x, y), g);
             build_frame(&mut imgbuf, gameboy.request_frame());
+
+            frames += 1;
+            if frames % SAVE_INTERVAL_FRAMES == 0 {
+                if let Err(e) = gameboy.save() {
+                    println!("err: could not write save file: {}", e);
+                }
+            }
             Some(())
         });
     }
+
+    if let Err(e) = gameboy.save() {
+        println!("err: could not write save file: {}", e);
+    }
 }
 
 fn get_projection_coordinates() -> (f64, f64) {
@@ -138,7 +326,7 @@ fn get_projection_coordinates() -> (f64, f64) {
     (x, y)
 }
 
-fn load_rom(fname: &str) -> std::io::Result<Vec<u8>> {
+fn load_rom<P: AsRef<Path>>(fname: P) -> std::io::Result<Vec<u8>> {
     let mut file = File::open(fname)?;
 
     let mut contents = Vec::new();